@@ -1,87 +1,358 @@
+use crate::{object::Object, settings::Settings};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub struct AnimationPlugin;
 
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreUpdate, animate);
+        app.register_type::<Animate>()
+            .add_event::<PlayAnimationEvent>()
+            .add_event::<AnimationFrameEvent>()
+            .add_systems(PreUpdate, (handle_play_animation_event, animate).chain());
     }
 }
 
+/************************************************************
+ * - Constants
+ */
+
+// Clip an Object starts with, and returns to once it's dropped
+pub const ANIMATION_DEFAULT_CLIP: &str = "idle";
+
 /************************************************************
  * - Types
  */
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectAnimationDesc {
     pub image_size: (usize, usize),
     pub atlas_size: (usize, usize),
     pub interval: f32,
+    // Named clips, an Object without an `idle` clip loops every frame of its atlas
+    #[serde(default)]
+    pub clips: HashMap<String, AnimationClipDesc>,
+}
+
+impl ObjectAnimationDesc {
+    pub fn clips(&self) -> HashMap<String, AnimationClip> {
+        return self
+            .clips
+            .iter()
+            .map(|(name, desc)| (name.clone(), AnimationClip::from_desc(desc, self.interval)))
+            .collect();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationClipDesc {
+    // Atlas indices in playback order
+    pub frames: Vec<usize>,
+    // Duration of each frame, frames without one use the interval of the animation
+    #[serde(default)]
+    pub durations: Vec<f32>,
+    pub mode: AnimationMode,
+    // Events sent when playback moves onto the given position of `frames`
+    #[serde(default)]
+    pub events: Vec<(usize, FrameEvent)>,
+    // Clip to continue with once a `Default` clip finishes
+    #[serde(default)]
+    pub next: Option<String>,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 pub enum AnimationMode {
     Default,
     Loop,
+    PingPong,
     Delete,
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Clone, Reflect, Serialize, Deserialize)]
+pub enum FrameEvent {
+    Sfx(String),
+    Signal(String),
+}
+
+#[derive(Debug, Clone, Reflect)]
+pub struct AnimationClip {
+    frames: Vec<usize>,
+    durations: Vec<f32>,
+    mode: AnimationMode,
+    events: Vec<(usize, FrameEvent)>,
+    next: Option<String>,
+}
+
+impl AnimationClip {
+    pub fn new(frame_count: usize, interval: f32, mode: AnimationMode) -> Self {
+        Self {
+            frames: (0..frame_count).collect(),
+            durations: vec![interval; frame_count],
+            mode,
+            events: vec![],
+            next: None,
+        }
+    }
+
+    pub fn from_desc(desc: &AnimationClipDesc, interval: f32) -> Self {
+        let durations = (0..desc.frames.len())
+            .map(|i| desc.durations.get(i).copied().unwrap_or(interval))
+            .collect();
+
+        Self {
+            frames: desc.frames.clone(),
+            durations,
+            mode: desc.mode,
+            events: desc.events.clone(),
+            next: desc.next.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component)]
 pub struct Animate {
     pub timer: Timer,
     pub current_frame: usize,
-    pub frame_count: usize,
-    mode: AnimationMode,
+    clip: AnimationClip,
+    clips: HashMap<String, AnimationClip>,
+    // PingPong clips play backwards every other pass
+    reverse: bool,
+    // Clip was just started, the events of its first frame are still to be sent
+    started: bool,
+}
+
+// What moving onto the next frame led to
+enum Advance {
+    Frame,
+    // `Default` clip ended without a clip to continue with
+    Finished,
+    Delete,
 }
 
 impl Animate {
     pub fn new(frame_count: usize, interval: f32, mode: AnimationMode) -> Self {
+        return Self::from_clip(AnimationClip::new(frame_count, interval, mode));
+    }
+
+    pub fn from_clip(clip: AnimationClip) -> Self {
         Self {
-            timer: Timer::from_seconds(interval, TimerMode::Repeating),
+            timer: Timer::from_seconds(clip.durations[0], TimerMode::Once),
             current_frame: 0,
-            frame_count,
-            mode,
+            clip,
+            clips: HashMap::new(),
+            reverse: false,
+            started: true,
         }
     }
+
+    // Starts with the `idle` clip when there is one
+    pub fn with_clips(mut self, clips: HashMap<String, AnimationClip>) -> Self {
+        self.clips = clips;
+        self.play(ANIMATION_DEFAULT_CLIP);
+
+        return self;
+    }
+
+    // Returns false if there is no clip with the given name
+    pub fn play(&mut self, name: &str) -> bool {
+        let clip = match self.clips.get(name) {
+            Some(clip) => clip.clone(),
+            None => return false,
+        };
+
+        self.timer = Timer::from_seconds(clip.durations[0], TimerMode::Once);
+        self.current_frame = 0;
+        self.reverse = false;
+        self.started = true;
+        self.clip = clip;
+
+        return true;
+    }
+
+    pub fn index(&self) -> usize {
+        return self.clip.frames[self.current_frame];
+    }
+
+    // Moves to the next frame
+    fn advance(&mut self) -> Advance {
+        let last = self.clip.frames.len() - 1;
+
+        if self.clip.mode == AnimationMode::PingPong && last > 0 {
+            if self.reverse && self.current_frame == 0 {
+                self.reverse = false;
+            } else if !self.reverse && self.current_frame == last {
+                self.reverse = true;
+            }
+
+            if self.reverse {
+                self.current_frame -= 1;
+            } else {
+                self.current_frame += 1;
+            }
+        } else if self.current_frame < last {
+            self.current_frame += 1;
+        } else {
+            match self.clip.mode {
+                AnimationMode::Default => {
+                    // The first frame of the next clip is sent like any other frame
+                    if let Some(next) = self.clip.next.clone() {
+                        if self.play(&next) {
+                            self.started = false;
+                            return Advance::Frame;
+                        }
+                    }
+
+                    // Stays on the last frame without repeating its events
+                    self.timer.pause();
+                    return Advance::Finished;
+                }
+                AnimationMode::Loop | AnimationMode::PingPong => {
+                    self.current_frame = 0;
+                }
+                AnimationMode::Delete => return Advance::Delete,
+            }
+        }
+
+        self.timer = Timer::from_seconds(self.clip.durations[self.current_frame], TimerMode::Once);
+
+        return Advance::Frame;
+    }
+
+    fn send_frame_events(
+        &self,
+        entity: Entity,
+        event_writer: &mut EventWriter<AnimationFrameEvent>,
+    ) {
+        for (frame, event) in &self.clip.events {
+            if *frame == self.current_frame {
+                event_writer.send(AnimationFrameEvent {
+                    entity,
+                    event: event.clone(),
+                });
+            }
+        }
+    }
+}
+
+impl Default for Animate {
+    // Single frame clip, for reflection
+    fn default() -> Self {
+        Self::new(1, 1.0, AnimationMode::Default)
+    }
+}
+
+#[derive(Debug, Event)]
+pub struct PlayAnimationEvent {
+    entity: Entity,
+    clip: String,
+    despawn: bool,
+}
+
+impl PlayAnimationEvent {
+    pub fn new(entity: Entity, clip: &str) -> Self {
+        Self {
+            entity,
+            clip: clip.to_string(),
+            despawn: false,
+        }
+    }
+
+    // Plays the clip once and deletes the entity, or deletes it right away without the clip
+    pub fn despawn(entity: Entity, clip: &str) -> Self {
+        Self {
+            entity,
+            clip: clip.to_string(),
+            despawn: true,
+        }
+    }
+}
+
+#[derive(Debug, Event)]
+pub struct AnimationFrameEvent {
+    pub entity: Entity,
+    pub event: FrameEvent,
 }
 
 /************************************************************
  * - System Functions
  */
 
+fn handle_play_animation_event(
+    mut commands: Commands,
+    mut event_reader: EventReader<PlayAnimationEvent>,
+    mut query: Query<&mut Animate>,
+) {
+    for event in event_reader.iter() {
+        let played = match query.get_mut(event.entity) {
+            Ok(mut animate) => animate.play(&event.clip),
+            Err(_) => false,
+        };
+
+        if !event.despawn {
+            continue;
+        }
+
+        if !played {
+            if let Some(entity) = commands.get_entity(event.entity) {
+                entity.despawn_recursive();
+            }
+            continue;
+        }
+
+        // Dying entity is no longer part of the board while the clip plays
+        if let Ok(mut animate) = query.get_mut(event.entity) {
+            animate.clip.mode = AnimationMode::Delete;
+        }
+        commands.entity(event.entity).remove::<Object>();
+    }
+}
+
 fn animate(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut Animate, &mut TextureAtlasSprite)>,
+    mut query: Query<(
+        Entity,
+        &mut Animate,
+        &mut TextureAtlasSprite,
+        Option<&Object>,
+    )>,
+    mut event_writer: EventWriter<AnimationFrameEvent>,
+    settings: Res<Settings>,
     time: Res<Time>,
 ) {
-    for (entity, mut animate, mut sprite) in &mut query {
-        animate.timer.tick(time.delta());
+    for (entity, mut animate, mut sprite, object) in &mut query {
+        if animate.started {
+            animate.started = false;
+            animate.send_frame_events(entity, &mut event_writer);
 
-        if animate.timer.just_finished() {
-            animate.current_frame += 1;
+            sprite.index = animate.index();
+        }
 
-            if animate.current_frame >= animate.frame_count {
-                match animate.mode {
-                    AnimationMode::Default => {
-                        animate.current_frame = animate.frame_count - 1;
-                    }
-                    AnimationMode::Loop => {
-                        animate.current_frame = 0;
-                    }
-                    AnimationMode::Delete => {
-                        commands.entity(entity).despawn_recursive();
-                        continue;
-                    }
+        // Only actors follow the animation speed, scene effects keep their own timing
+        if object.is_some() {
+            animate
+                .timer
+                .tick(time.delta().mul_f32(settings.gameplay.animation_speed));
+        } else {
+            animate.timer.tick(time.delta());
+        }
+
+        if animate.timer.just_finished() {
+            match animate.advance() {
+                Advance::Frame => {}
+                Advance::Finished => continue,
+                Advance::Delete => {
+                    commands.entity(entity).despawn_recursive();
+                    continue;
                 }
             }
 
-            *sprite = TextureAtlasSprite {
-                index: animate.current_frame,
-                anchor: sprite.anchor.clone(),
-                ..Default::default()
-            };
+            animate.send_frame_events(entity, &mut event_writer);
+
+            // Only the frame changes, flipped Objects keep their flip
+            sprite.index = animate.index();
         }
     }
 }
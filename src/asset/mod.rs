@@ -1,27 +1,45 @@
-use crate::audio::AudioMode;
+use crate::{
+    audio::{AudioBus, AudioMode},
+    error::{self, GameError, GameResult},
+    scene::error::GameErrorEvent,
+    settings::AudioSettings,
+};
+use bevy::asset::HandleId;
+use bevy::audio::{PlaybackMode, Volume};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod watch;
 
 pub struct AssetPlugin;
 
 impl Plugin for AssetPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, load_assets);
+    }
 }
 
+/************************************************************
+ * - Constants
+ */
+
+const ASSET_MANIFEST_PATH: &str = "assets/asset-manifest.ron";
+
 /************************************************************
  * - Types
  */
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TextureDesc {
-    path: String,
-    size: UVec2,
+    pub path: String,
+    pub size: UVec2,
 }
 
 #[derive(Debug)]
 pub struct TextureAsset {
-    handle: Handle<Image>,
-    desc: TextureDesc,
+    pub handle: Handle<Image>,
+    pub desc: TextureDesc,
 }
 
 impl TextureAsset {
@@ -35,15 +53,22 @@ impl TextureAsset {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TextureAtlasDesc {
-    path: String,
-    tile: UVec2,
-    size: UVec2,
+    pub path: String,
+    pub tile: UVec2,
+    pub size: UVec2,
+}
+
+impl TextureAtlasDesc {
+    pub fn frame_count(&self) -> usize {
+        return (self.size.x * self.size.y) as usize;
+    }
 }
 
 #[derive(Debug)]
 pub struct TextureAtlasAsset {
-    handle: Handle<TextureAtlas>,
-    desc: TextureAtlasDesc,
+    pub handle: Handle<TextureAtlas>,
+    pub image: Handle<Image>,
+    pub desc: TextureAtlasDesc,
 }
 
 impl TextureAtlasAsset {
@@ -52,9 +77,11 @@ impl TextureAtlasAsset {
         texture_atlases: &mut Assets<TextureAtlas>,
         asset_server: &AssetServer,
     ) -> Self {
+        let image = asset_server.load(&desc.path);
+
         let texture_atlas = TextureAtlas::from_grid(
-            asset_server.load(&desc.path),
-            Vec2::new(desc.tile.y as f32, desc.tile.y as f32),
+            image.clone(),
+            Vec2::new(desc.tile.x as f32, desc.tile.y as f32),
             desc.size.x as usize,
             desc.size.y as usize,
             None,
@@ -63,6 +90,7 @@ impl TextureAtlasAsset {
 
         Self {
             handle: texture_atlases.add(texture_atlas),
+            image,
             desc,
         }
     }
@@ -70,15 +98,17 @@ impl TextureAtlasAsset {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AudioDesc {
-    path: String,
-    mode: AudioMode,
-    volume: f32,
+    pub path: String,
+    pub mode: AudioMode,
+    pub volume: f32,
+    #[serde(default)]
+    pub bus: AudioBus,
 }
 
 #[derive(Debug)]
 pub struct AudioAsset {
-    handle: Handle<AudioSource>,
-    desc: AudioDesc,
+    pub handle: Handle<AudioSource>,
+    pub desc: AudioDesc,
 }
 
 impl AudioAsset {
@@ -88,15 +118,137 @@ impl AudioAsset {
             desc,
         }
     }
+
+    pub fn bundle(&self, settings: &AudioSettings) -> AudioBundle {
+        return AudioBundle {
+            source: self.handle.clone(),
+            settings: PlaybackSettings {
+                mode: match self.desc.mode {
+                    AudioMode::Once => PlaybackMode::Once,
+                    AudioMode::Loop => PlaybackMode::Loop,
+                    AudioMode::Despawn => PlaybackMode::Despawn,
+                },
+                ..Default::default()
+            }
+            .with_volume(Volume::new_relative(self.volume(settings))),
+        };
+    }
+
+    // Volume of the asset scaled by the master and bus sliders
+    pub fn volume(&self, settings: &AudioSettings) -> f32 {
+        return self.desc.volume * settings.volume(self.desc.bus);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitmapFontDesc {
+    // Id of the atlas in the manifest holding the glyphs
+    pub atlas: String,
+    // Characters in the order of the atlas frames
+    pub glyphs: String,
+    // Pixels between glyphs, on top of the tile width
+    pub spacing: u32,
+    pub line_height: u32,
+}
+
+#[derive(Debug)]
+pub struct BitmapFontAsset {
+    pub handle: Handle<TextureAtlas>,
+    pub tile: UVec2,
+    glyphs: HashMap<char, usize>,
+    pub desc: BitmapFontDesc,
+}
+
+impl BitmapFontAsset {
+    fn new(desc: BitmapFontDesc, atlas: &TextureAtlasAsset) -> Self {
+        Self {
+            handle: atlas.handle.clone(),
+            tile: atlas.desc.tile,
+            glyphs: desc
+                .glyphs
+                .chars()
+                .enumerate()
+                .map(|(i, c)| (c, i))
+                .collect(),
+            desc,
+        }
+    }
+
+    // Falls back to the uppercase glyph for fonts without lowercase letters
+    pub fn glyph(&self, c: char) -> Option<usize> {
+        return self
+            .glyphs
+            .get(&c)
+            .or_else(|| self.glyphs.get(&c.to_ascii_uppercase()))
+            .copied();
+    }
+
+    pub fn advance(&self) -> u32 {
+        return self.tile.x + self.desc.spacing;
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AssetManifest {
+    textures: HashMap<String, TextureDesc>,
+    atlases: HashMap<String, TextureAtlasDesc>,
+    audio: HashMap<String, AudioDesc>,
+    #[serde(default)]
+    fonts: HashMap<String, BitmapFontDesc>,
 }
 
 #[derive(Debug, Resource)]
 pub struct GameAssetServer {
+    textures: HashMap<String, TextureAsset>,
+    atlases: HashMap<String, TextureAtlasAsset>,
+    audio: HashMap<String, AudioAsset>,
+    fonts: HashMap<String, BitmapFontAsset>,
 }
 
 impl GameAssetServer {
-    fn get(path: &str) -> {
-        
+    fn new() -> Self {
+        Self {
+            textures: HashMap::new(),
+            atlases: HashMap::new(),
+            audio: HashMap::new(),
+            fonts: HashMap::new(),
+        }
+    }
+
+    pub fn texture(&self, id: &str) -> GameResult<&TextureAsset> {
+        return self.textures.get(id).ok_or_else(|| missing("texture", id));
+    }
+
+    pub fn atlas(&self, id: &str) -> GameResult<&TextureAtlasAsset> {
+        return self
+            .atlases
+            .get(id)
+            .ok_or_else(|| missing("texture atlas", id));
+    }
+
+    pub fn audio(&self, id: &str) -> GameResult<&AudioAsset> {
+        return self.audio.get(id).ok_or_else(|| missing("audio", id));
+    }
+
+    pub fn font(&self, id: &str) -> GameResult<&BitmapFontAsset> {
+        return self.fonts.get(id).ok_or_else(|| missing("font", id));
+    }
+
+    // Every handle in the manifest paired with the path it's loaded from
+    pub fn handles(&self) -> Vec<(&str, HandleId)> {
+        let mut handles = vec![];
+
+        for asset in self.textures.values() {
+            handles.push((asset.desc.path.as_str(), asset.handle.id()));
+        }
+        for asset in self.atlases.values() {
+            handles.push((asset.desc.path.as_str(), asset.image.id()));
+        }
+        for asset in self.audio.values() {
+            handles.push((asset.desc.path.as_str(), asset.handle.id()));
+        }
+
+        return handles;
     }
 }
 
@@ -104,4 +256,64 @@ impl GameAssetServer {
  * - System Functions
  */
 
-fn load_assets() {}
+fn load_assets(
+    mut commands: Commands,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    asset_server: Res<AssetServer>,
+) {
+    let mut gas = GameAssetServer::new();
+
+    // Every scene depends on the manifest, without it the server stays empty
+    // and the error scene is shown instead
+    let manifest: AssetManifest = match error::read_ron(ASSET_MANIFEST_PATH) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            commands.insert_resource(gas);
+            return;
+        }
+    };
+
+    for (id, desc) in manifest.textures {
+        gas.textures
+            .insert(id, TextureAsset::new(desc, &asset_server));
+    }
+
+    for (id, desc) in manifest.atlases {
+        gas.atlases.insert(
+            id,
+            TextureAtlasAsset::new(desc, &mut texture_atlases, &asset_server),
+        );
+    }
+
+    for (id, desc) in manifest.audio {
+        gas.audio.insert(id, AudioAsset::new(desc, &asset_server));
+    }
+
+    // Fonts share the handle of their atlas, so they're loaded last
+    for (id, desc) in manifest.fonts {
+        let font = match gas.atlas(&desc.atlas) {
+            Ok(atlas) => BitmapFontAsset::new(desc, atlas),
+            Err(e) => {
+                error_writer.send(GameErrorEvent::new(e));
+                continue;
+            }
+        };
+
+        gas.fonts.insert(id, font);
+    }
+
+    commands.insert_resource(gas);
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn missing(kind: &'static str, id: &str) -> GameError {
+    return GameError::MissingAsset {
+        kind,
+        id: id.to_string(),
+    };
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use std::time::{Duration, SystemTime};
+
+/************************************************************
+ * - Constants
+ */
+
+const FILE_WATCHER_POLL_INTERVAL: f32 = 0.5;
+
+/************************************************************
+ * - Types
+ */
+
+// Polls the modification time of a file that is read outside of the AssetServer
+#[derive(Debug)]
+pub struct FileWatcher {
+    path: String,
+    modified: Option<SystemTime>,
+    timer: Timer,
+}
+
+impl FileWatcher {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            modified: modified_time(path),
+            timer: Timer::from_seconds(FILE_WATCHER_POLL_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        return &self.path;
+    }
+
+    // Returns true once for every time the file is saved
+    pub fn changed(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta);
+
+        if !self.timer.just_finished() {
+            return false;
+        }
+
+        let modified = modified_time(&self.path);
+
+        if modified.is_none() || modified == self.modified {
+            return false;
+        }
+
+        self.modified = modified;
+
+        return true;
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    return std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+}
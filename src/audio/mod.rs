@@ -1,13 +1,18 @@
-use bevy::{
-    audio::{Volume, VolumeLevel},
-    prelude::*,
+use crate::{
+    animation::{AnimationFrameEvent, FrameEvent},
+    asset::GameAssetServer,
+    scene::error::GameErrorEvent,
+    settings::Settings,
 };
+use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 pub struct AudioPlugin;
 
 impl Plugin for AudioPlugin {
-    fn build(&self, app: &mut App) {}
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (play_frame_sfx, apply_audio_settings));
+    }
 }
 
 /************************************************************
@@ -21,9 +26,24 @@ pub enum AudioMode {
     Despawn,
 }
 
+// Volume slider an audio asset is mixed by
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioBus {
+    Music,
+    #[default]
+    Sfx,
+}
+
 #[derive(Debug, Component)]
 pub struct AudioPlayer;
 
+// Playing sound with the volume of its asset, so the sink can follow the settings
+#[derive(Debug, Component)]
+pub struct AudioVolume {
+    pub bus: AudioBus,
+    pub volume: f32,
+}
+
 /************************************************************
  * - Service Functions
  */
@@ -35,3 +55,41 @@ pub fn spawn_audio_player(commands: &mut Commands) -> Entity {
 /************************************************************
  * - System Functions
  */
+
+fn play_frame_sfx(
+    mut commands: Commands,
+    mut event_reader: EventReader<AnimationFrameEvent>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
+    settings: Res<Settings>,
+) {
+    for event in event_reader.iter() {
+        if let FrameEvent::Sfx(id) = &event.event {
+            let asset = match gas.audio(id) {
+                Ok(asset) => asset,
+                Err(e) => {
+                    error_writer.send(GameErrorEvent::new(e));
+                    continue;
+                }
+            };
+
+            commands.spawn((
+                asset.bundle(&settings.audio),
+                AudioVolume {
+                    bus: asset.desc.bus,
+                    volume: asset.desc.volume,
+                },
+            ));
+        }
+    }
+}
+
+fn apply_audio_settings(query: Query<(&AudioSink, &AudioVolume)>, settings: Res<Settings>) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (sink, volume) in &query {
+        sink.set_volume(volume.volume * settings.audio.volume(volume.bus));
+    }
+}
@@ -1,12 +1,30 @@
-use crate::global;
+use crate::{asset::GameAssetServer, global, scene::error::GameErrorEvent, state::AppState};
 use bevy::prelude::*;
+use bevy::render::camera::{CameraOutputMode, ScalingMode, Viewport};
+use bevy::render::render_resource::LoadOp;
 use bevy::sprite::Anchor;
+use bevy::transform::TransformSystem;
+use bevy::window::PrimaryWindow;
 
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, (setup_camera, setup_background));
+        app.insert_resource(PixelScale(global::window::SCALE_FACTOR as u32))
+            .add_event::<CameraEffectEvent>()
+            .add_systems(PreStartup, setup_camera)
+            .add_systems(Startup, setup_background)
+            .add_systems(OnExit(AppState::Game), reset_camera_effects)
+            .add_systems(
+                PostUpdate,
+                (
+                    update_camera_viewport,
+                    update_letterbox_color,
+                    (handle_camera_effect_event, update_camera_effects)
+                        .chain()
+                        .before(TransformSystem::TransformPropagate),
+                ),
+            );
     }
 }
 
@@ -14,15 +32,72 @@ impl Plugin for CameraPlugin {
  * - Constants
  */
 
-const BACKGROUND_IMAGE_PATH: &str = "background.png";
+// How quickly the camera catches up with where the effects want it, per second
+const CAMERA_SMOOTHING: f32 = 12.0;
+
+// Fraction of the distance to the focus the camera pans towards
+const CAMERA_PAN_AMOUNT: f32 = 0.25;
+
+const CAMERA_SHAKE_FREQUENCY: (f32, f32) = (47.0, 59.0);
 
 /************************************************************
- * - Constants
+ * - Types
  */
 
 #[derive(Debug, Component)]
 struct MainCamera;
 
+#[derive(Debug, Event)]
+pub enum CameraEffectEvent {
+    // Strength in viewport pixels, fading out over the duration
+    Shake { strength: f32, duration: f32 },
+    // Zooms in by the amount and springs back over the duration
+    Punch { amount: f32, duration: f32 },
+    // Leans towards a viewport position, and back after the duration
+    Pan { focus: Vec2, duration: f32 },
+}
+
+impl CameraEffectEvent {
+    pub fn shake(strength: f32, duration: f32) -> Self {
+        return Self::Shake { strength, duration };
+    }
+
+    pub fn punch(amount: f32, duration: f32) -> Self {
+        return Self::Punch { amount, duration };
+    }
+
+    pub fn pan(focus: Vec2, duration: f32) -> Self {
+        return Self::Pan { focus, duration };
+    }
+}
+
+#[derive(Debug, Component)]
+struct CameraEffects {
+    // Center of the viewport, where the camera rests without effects
+    rest: Vec2,
+    // Smoothed position before pixel snapping
+    position: Vec2,
+    shake: Option<(f32, Timer)>,
+    punch: Option<(f32, Timer)>,
+    pan: Option<(Vec2, Timer)>,
+}
+
+impl CameraEffects {
+    fn new(rest: Vec2) -> Self {
+        Self {
+            rest,
+            position: rest,
+            shake: None,
+            punch: None,
+            pan: None,
+        }
+    }
+}
+
+// Integer scale the viewport is currently rendered at, in physical pixels
+#[derive(Debug, Resource)]
+pub struct PixelScale(pub u32);
+
 /************************************************************
  * - System Functions
  */
@@ -30,7 +105,10 @@ struct MainCamera;
 fn setup_camera(mut commands: Commands) {
     let mut camera = Camera2dBundle::default();
 
-    camera.projection.scale = 1.0 / global::window::SCALE_FACTOR as f32;
+    camera.projection.scaling_mode = ScalingMode::Fixed {
+        width: global::window::VIEWPORT_RESOLUTION.0 as f32,
+        height: global::window::VIEWPORT_RESOLUTION.1 as f32,
+    };
 
     camera.transform.translation = Vec3::new(
         global::window::VIEWPORT_RESOLUTION.0 as f32 / 2.0,
@@ -38,19 +116,228 @@ fn setup_camera(mut commands: Commands) {
         0.0,
     );
 
-    commands.spawn((camera, MainCamera, Name::new("Main Camera")));
+    let rest = camera.transform.translation.truncate();
+
+    commands.spawn((
+        camera,
+        MainCamera,
+        CameraEffects::new(rest),
+        Name::new("Main Camera"),
+    ));
 }
 
-fn setup_background(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_background(
+    mut commands: Commands,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
+) {
+    let asset = match gas.texture("background") {
+        Ok(asset) => asset,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    };
+
     commands.spawn((
         SpriteBundle {
             sprite: Sprite {
                 anchor: Anchor::BottomLeft,
                 ..Default::default()
             },
-            texture: asset_server.load(BACKGROUND_IMAGE_PATH),
+            texture: asset.handle.clone(),
             ..Default::default()
         },
         Name::new("Background Image"),
     ));
 }
+
+fn update_camera_viewport(
+    mut camera_query: Query<&mut Camera, With<MainCamera>>,
+    window_query: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut pixel_scale: ResMut<PixelScale>,
+) {
+    let window = match window_query.get_single() {
+        Ok(window) => window,
+        Err(_) => return,
+    };
+
+    let size = UVec2::new(window.physical_width(), window.physical_height());
+
+    // Window is minimized
+    if size.x == 0 || size.y == 0 {
+        return;
+    }
+
+    // Largest integer scale the viewport fits into the window with
+    let scale = (size.x / global::window::VIEWPORT_RESOLUTION.0 as u32)
+        .min(size.y / global::window::VIEWPORT_RESOLUTION.1 as u32)
+        .max(1);
+
+    let viewport_size = UVec2::new(
+        global::window::VIEWPORT_RESOLUTION.0 as u32 * scale,
+        global::window::VIEWPORT_RESOLUTION.1 as u32 * scale,
+    )
+    .min(size);
+
+    let viewport = Viewport {
+        physical_position: (size - viewport_size) / 2,
+        physical_size: viewport_size,
+        ..Default::default()
+    };
+
+    for mut camera in &mut camera_query {
+        let changed = match &camera.viewport {
+            Some(current) => {
+                current.physical_position != viewport.physical_position
+                    || current.physical_size != viewport.physical_size
+            }
+            None => true,
+        };
+
+        if changed {
+            camera.viewport = Some(viewport.clone());
+        }
+    }
+
+    if pixel_scale.0 != scale {
+        pixel_scale.0 = scale;
+    }
+}
+
+fn update_letterbox_color(
+    mut camera_query: Query<&mut Camera, With<MainCamera>>,
+    clear_color: Res<ClearColor>,
+) {
+    if !clear_color.is_changed() {
+        return;
+    }
+
+    // Area outside of the viewport is cleared with the ClearColor as well
+    for mut camera in &mut camera_query {
+        camera.output_mode = CameraOutputMode::Write {
+            blend_state: None,
+            color_attachment_load_op: LoadOp::Clear(clear_color.0.into()),
+        };
+    }
+}
+
+fn handle_camera_effect_event(
+    mut query: Query<&mut CameraEffects, With<MainCamera>>,
+    mut event_reader: EventReader<CameraEffectEvent>,
+) {
+    for event in event_reader.iter() {
+        for mut effects in &mut query {
+            match *event {
+                CameraEffectEvent::Shake { strength, duration } => {
+                    // A weaker shake doesn't cut a stronger one short
+                    if let Some((current, timer)) = &effects.shake {
+                        if *current * timer.percent_left() > strength {
+                            continue;
+                        }
+                    }
+
+                    effects.shake =
+                        Some((strength, Timer::from_seconds(duration, TimerMode::Once)));
+                }
+                CameraEffectEvent::Punch { amount, duration } => {
+                    effects.punch = Some((amount, Timer::from_seconds(duration, TimerMode::Once)));
+                }
+                CameraEffectEvent::Pan { focus, duration } => {
+                    effects.pan = Some((focus, Timer::from_seconds(duration, TimerMode::Once)));
+                }
+            }
+        }
+    }
+}
+
+// Other scenes are drawn against the resting camera
+fn reset_camera_effects(
+    mut query: Query<
+        (
+            &mut CameraEffects,
+            &mut Transform,
+            &mut OrthographicProjection,
+        ),
+        With<MainCamera>,
+    >,
+) {
+    for (mut effects, mut transform, mut projection) in &mut query {
+        *effects = CameraEffects::new(effects.rest);
+
+        transform.translation.x = effects.rest.x;
+        transform.translation.y = effects.rest.y;
+        projection.scale = 1.0;
+    }
+}
+
+fn update_camera_effects(
+    mut query: Query<
+        (
+            &mut CameraEffects,
+            &mut Transform,
+            &mut OrthographicProjection,
+        ),
+        With<MainCamera>,
+    >,
+    pixel_scale: Res<PixelScale>,
+    time: Res<Time>,
+) {
+    for (mut effects, mut transform, mut projection) in &mut query {
+        let rest = effects.rest;
+        let mut target = rest;
+        let mut offset = Vec2::ZERO;
+        let mut scale = 1.0;
+
+        if let Some((focus, timer)) = &mut effects.pan {
+            timer.tick(time.delta());
+
+            target += (*focus - rest) * CAMERA_PAN_AMOUNT;
+
+            if timer.finished() {
+                effects.pan = None;
+            }
+        }
+
+        // Shake and punch are applied on top of the smoothed position, so they stay snappy
+        if let Some((strength, timer)) = &mut effects.shake {
+            timer.tick(time.delta());
+
+            let t = time.elapsed_seconds();
+            let strength = *strength * timer.percent_left();
+
+            offset = Vec2::new(
+                (t * CAMERA_SHAKE_FREQUENCY.0).sin(),
+                (t * CAMERA_SHAKE_FREQUENCY.1).cos(),
+            ) * strength;
+
+            if timer.finished() {
+                effects.shake = None;
+            }
+        }
+
+        if let Some((amount, timer)) = &mut effects.punch {
+            timer.tick(time.delta());
+
+            scale -= *amount * timer.percent_left();
+
+            if timer.finished() {
+                effects.punch = None;
+            }
+        }
+
+        let smoothing = 1.0 - (-CAMERA_SMOOTHING * time.delta_seconds()).exp();
+        effects.position = effects.position.lerp(target, smoothing);
+
+        // Snap to the pixel grid of the current integer scale
+        let pixel = pixel_scale.0.max(1) as f32;
+        let position = ((effects.position + offset) * pixel).round() / pixel;
+
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+
+        if projection.scale != scale {
+            projection.scale = scale;
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::object::ObjectID;
+use bevy::prelude::*;
+use std::fmt;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug)]
+pub enum GameError {
+    Io {
+        path: String,
+        error: std::io::Error,
+    },
+    Parse {
+        path: String,
+        error: ron::error::SpannedError,
+    },
+    Serialize {
+        path: String,
+        error: ron::Error,
+    },
+    MissingObjectConf(ObjectID),
+    InvalidPosition {
+        id: ObjectID,
+        position: IVec2,
+    },
+    OverlappingObjects {
+        id: ObjectID,
+        position: IVec2,
+    },
+    DuplicateObject(ObjectID),
+    MissingObject(ObjectID),
+    InvalidLevel(usize),
+    InvalidGridMask(usize),
+    InvalidUFOStart(IVec2),
+    MissingProfile(String),
+    GenerationFailed(u64),
+    InvalidNotation {
+        line: usize,
+        reason: String,
+    },
+    MissingAsset {
+        kind: &'static str,
+        id: String,
+    },
+    EmptyAnimationClip {
+        id: ObjectID,
+        clip: String,
+    },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "Failed to read `{}`, {}.", path, error),
+            Self::Parse { path, error } => write!(f, "Failed to parse `{}`, {}.", path, error),
+            Self::Serialize { path, error } => {
+                write!(f, "Failed to serialize `{}`, {}.", path, error)
+            }
+            Self::MissingObjectConf(id) => {
+                write!(f, "Failed to get object configuration for {}.", id)
+            }
+            Self::InvalidPosition { id, position } => write!(
+                f,
+                "{} is placed outside of the grid at ({}, {}).",
+                id, position.x, position.y
+            ),
+            Self::OverlappingObjects { id, position } => write!(
+                f,
+                "{} overlaps another object at ({}, {}).",
+                id, position.x, position.y
+            ),
+            Self::DuplicateObject(id) => {
+                write!(f, "There can't be more than one {} in a level.", id)
+            }
+            Self::MissingObject(id) => {
+                write!(f, "Encountered incomplete level, {} is missing.", id)
+            }
+            Self::InvalidLevel(level) => write!(f, "Level #{} doesn't exist.", level),
+            Self::InvalidGridMask(len) => {
+                write!(
+                    f,
+                    "Grid mask has {} cells, which doesn't match the grid.",
+                    len
+                )
+            }
+            Self::InvalidUFOStart(position) => write!(
+                f,
+                "UFO starts outside of the grid at ({}, {}).",
+                position.x, position.y
+            ),
+            Self::MissingProfile(name) => write!(f, "Generator profile `{}` doesn't exist.", name),
+            Self::GenerationFailed(seed) => {
+                write!(f, "Failed to generate a solvable level from seed {}.", seed)
+            }
+            Self::InvalidNotation { line, reason } => {
+                write!(f, "Invalid board notation on line {}, {}.", line, reason)
+            }
+            Self::MissingAsset { kind, id } => {
+                write!(
+                    f,
+                    "Failed to get {} `{}` from the asset manifest.",
+                    kind, id
+                )
+            }
+            Self::EmptyAnimationClip { id, clip } => {
+                write!(f, "Animation clip `{}` of {} has no frames.", clip, id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+pub type GameResult<T> = Result<T, GameError>;
+
+/************************************************************
+ * - Helper Functions
+ */
+
+pub fn read_ron<T: serde::de::DeserializeOwned>(path: &str) -> GameResult<T> {
+    let contents = std::fs::read_to_string(path).map_err(|error| GameError::Io {
+        path: path.to_string(),
+        error,
+    })?;
+
+    return ron::from_str(&contents).map_err(|error| GameError::Parse {
+        path: path.to_string(),
+        error,
+    });
+}
+
+pub fn write_ron<T: serde::Serialize>(path: &str, value: &T) -> GameResult<()> {
+    let contents =
+        ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()).map_err(|error| {
+            GameError::Serialize {
+                path: path.to_string(),
+                error,
+            }
+        })?;
+
+    return std::fs::write(path, contents).map_err(|error| GameError::Io {
+        path: path.to_string(),
+        error,
+    });
+}
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+mod preview;
+pub mod record;
+pub mod rng;
 pub mod ufo;
 mod warn;
 pub mod win;
@@ -9,6 +12,9 @@ pub struct GamePlugin;
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<GameState>()
+            .insert_resource(rng::GameRng::new())
+            .add_plugins(preview::DropPreviewPlugin)
+            .add_plugins(record::RecordPlugin)
             .add_plugins(warn::WarningPlugin)
             .add_plugins(win::WinPlugin)
             .add_plugins(ufo::UFOPlugin);
@@ -25,4 +31,5 @@ pub enum GameState {
     PlayerControlled,
     ObjectControlled,
     Paused,
+    Settings,
 }
@@ -0,0 +1,309 @@
+use super::{
+    ufo::{self, UFO},
+    GameState,
+};
+use crate::{
+    object::{
+        asset::ObjectAssetServer,
+        find_valid_cells,
+        turn::{find_path, predict_turn},
+        Object, ObjectID,
+    },
+    render::{RenderLayer, RENDER_LAYER},
+    state::AppState,
+    tween::TweenSystem,
+    ui::bitmap_text::{BitmapText, BitmapTextSystem, TextAlign},
+    world::{
+        grid::Grid,
+        tile::{TileState, TileStateChangeEvent},
+        World,
+    },
+};
+use bevy::prelude::*;
+
+pub struct DropPreviewPlugin;
+
+impl Plugin for DropPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DropPreview>()
+            .add_systems(
+                PostUpdate,
+                update_drop_preview
+                    // Runs after the UFO handlers, so the path is drawn over the tiles they select
+                    .after(TweenSystem)
+                    .before(BitmapTextSystem)
+                    .run_if(
+                        in_state(AppState::Game).and_then(in_state(GameState::PlayerControlled)),
+                    ),
+            )
+            .add_systems(OnExit(AppState::Game), reset_drop_preview);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+// Top of the label, centered in the gap between the HUD and the board
+const PATH_PREVIEW_LABEL_POSITION: (f32, f32) = (80.0, 34.0);
+
+const PATH_PREVIEW_OPEN_LABEL: &str = "PATH OPEN";
+const PATH_PREVIEW_OPEN_COLOR: Color = Color::rgb(0.6, 1.0, 0.6);
+
+const PATH_PREVIEW_BLOCKED_LABEL: &str = "PATH BLOCKED";
+const PATH_PREVIEW_BLOCKED_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+
+const TURN_PREVIEW_MARKER_SIZE: f32 = 4.0;
+
+const TURN_PREVIEW_CONSUMED_COLOR: Color = Color::rgb(0.4, 0.8, 1.0);
+const TURN_PREVIEW_KILLED_COLOR: Color = Color::rgb(1.0, 0.2, 0.2);
+const TURN_PREVIEW_WANDER_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.7);
+
+const TURN_PREVIEW_WANDER_FONT: &str = "ui_numbers";
+
+/************************************************************
+ * - Types
+ */
+
+// What dropping the carried Object would lead to, the King's path and the turn after it
+#[derive(Debug, Default, Resource)]
+struct DropPreview {
+    // Carried Object, its orientation and drop target the preview was made for
+    shown_for: Option<(Entity, usize, IVec2)>,
+    // Tiles currently switched to `TileState::Path`
+    path: Vec<IVec2>,
+    label: Option<Entity>,
+    // Predicted turn outcome drawn over the board
+    markers: Vec<Entity>,
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn update_drop_preview(
+    mut commands: Commands,
+    mut preview: ResMut<DropPreview>,
+    mut tile_event_writer: EventWriter<TileStateChangeEvent>,
+    objects: Query<(Entity, &Object)>,
+    ufo_query: Query<&UFO>,
+    oas: Res<ObjectAssetServer>,
+    world: Res<World>,
+    grid: Res<Grid>,
+) {
+    let ufo = match ufo_query.get_single() {
+        Ok(ufo) => ufo,
+        Err(_) => return,
+    };
+
+    let carried = ufo.carried().and_then(|(entity, orientation)| {
+        let (entity, object) = objects.get(entity).ok()?;
+        let asset = oas.get(object.id).ok()?;
+        return Some((entity, object, orientation, ufo.drop_target(asset)?));
+    });
+
+    let (entity, object, orientation, target) = match carried {
+        Some(carried) => carried,
+        None => {
+            // The drop and cancel handlers already cleared the valid cells
+            if preview.shown_for.is_some() {
+                for cell in std::mem::take(&mut preview.path) {
+                    tile_event_writer.send(TileStateChangeEvent::new(
+                        cell,
+                        if cell == ufo.position {
+                            TileState::Selected
+                        } else {
+                            TileState::Default
+                        },
+                    ));
+                }
+
+                despawn_preview_entities(&mut preview, &mut commands);
+
+                preview.shown_for = None;
+            }
+            return;
+        }
+    };
+
+    if preview.shown_for == Some((entity, orientation, target)) {
+        return;
+    }
+    preview.shown_for = Some((entity, orientation, target));
+
+    // Refused drops leave the Object where it was lifted from
+    let dropped = ufo::simulate_drop(entity, object, target, orientation, &oas, &world, &grid)
+        .unwrap_or(world.clone());
+
+    let king = objects.iter().find_map(|(king, king_object)| {
+        if king_object.id != ObjectID::King {
+            return None;
+        }
+
+        return Some(if king == entity {
+            target
+        } else {
+            king_object.occupied[0]
+        });
+    });
+
+    let path = match king {
+        Some(start) => find_path(start, &dropped, &grid).map(|(path, _)| path),
+        None => None,
+    };
+    let reached = path.is_some();
+
+    // The hovered tile keeps showing where the UFO is
+    let path: Vec<IVec2> = path
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|cell| *cell != ufo.position)
+        .collect();
+
+    // Tiles leaving the path go back to how the lift left them
+    let valid = find_valid_cells(
+        entity,
+        object.id,
+        object.occupied[0],
+        orientation,
+        &oas,
+        &world,
+        &grid,
+    );
+    for cell in &preview.path {
+        if path.contains(cell) {
+            continue;
+        }
+
+        tile_event_writer.send(TileStateChangeEvent::new(
+            *cell,
+            if valid.contains(cell) {
+                TileState::Selected
+            } else {
+                TileState::Default
+            },
+        ));
+    }
+
+    for cell in &path {
+        tile_event_writer.send(TileStateChangeEvent::new(*cell, TileState::Path));
+    }
+
+    let (text, color) = if reached {
+        (PATH_PREVIEW_OPEN_LABEL, PATH_PREVIEW_OPEN_COLOR)
+    } else {
+        (PATH_PREVIEW_BLOCKED_LABEL, PATH_PREVIEW_BLOCKED_COLOR)
+    };
+
+    preview.path = path;
+
+    despawn_preview_entities(&mut preview, &mut commands);
+
+    preview.label = Some(
+        commands
+            .spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    PATH_PREVIEW_LABEL_POSITION.0,
+                    PATH_PREVIEW_LABEL_POSITION.1,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                )),
+                BitmapText::new(text)
+                    .with_align(TextAlign::Center)
+                    .with_color(color),
+                Name::new("Path Preview Label"),
+            ))
+            .id(),
+    );
+
+    // Units as they'd stand once the Object is dropped
+    let units: Vec<(ObjectID, IVec2)> = objects
+        .iter()
+        .filter_map(|(unit, unit_object)| {
+            let position = if unit == entity {
+                target
+            } else {
+                unit_object.occupied[0]
+            };
+
+            return match unit_object.id {
+                ObjectID::Villager | ObjectID::Cow | ObjectID::Assassin => {
+                    Some((unit_object.id, position))
+                }
+                _ => None,
+            };
+        })
+        .collect();
+
+    let prediction = predict_turn(&units, &dropped, &grid);
+
+    for cell in prediction.consumed {
+        let marker = spawn_marker(cell, TURN_PREVIEW_CONSUMED_COLOR, &grid, &mut commands);
+        preview.markers.push(marker);
+    }
+    for cell in prediction.killed {
+        let marker = spawn_marker(cell, TURN_PREVIEW_KILLED_COLOR, &grid, &mut commands);
+        preview.markers.push(marker);
+    }
+    for (cell, chance) in prediction.wander {
+        let center = grid.cell_center(UVec2::new(cell.x as u32, cell.y as u32));
+
+        let marker = commands
+            .spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    center.x,
+                    center.y + 4.0,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                )),
+                BitmapText::new(&format!("{}", (chance * 100.0).round() as u32))
+                    .with_font(TURN_PREVIEW_WANDER_FONT)
+                    .with_align(TextAlign::Center)
+                    .with_color(TURN_PREVIEW_WANDER_COLOR),
+                Name::new("Turn Preview Wander"),
+            ))
+            .id();
+        preview.markers.push(marker);
+    }
+}
+
+fn reset_drop_preview(mut commands: Commands, mut preview: ResMut<DropPreview>) {
+    despawn_preview_entities(&mut preview, &mut commands);
+
+    *preview = DropPreview::default();
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn despawn_preview_entities(preview: &mut DropPreview, commands: &mut Commands) {
+    if let Some(label) = preview.label.take() {
+        commands.entity(label).despawn_recursive();
+    }
+
+    for marker in preview.markers.drain(..) {
+        commands.entity(marker).despawn_recursive();
+    }
+}
+
+fn spawn_marker(cell: IVec2, color: Color, grid: &Grid, commands: &mut Commands) -> Entity {
+    let center = grid.cell_center(UVec2::new(cell.x as u32, cell.y as u32));
+
+    return commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(TURN_PREVIEW_MARKER_SIZE)),
+                    ..Default::default()
+                },
+                transform: Transform::from_xyz(
+                    center.x,
+                    center.y,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                ),
+                ..Default::default()
+            },
+            Name::new("Turn Preview Marker"),
+        ))
+        .id();
+}
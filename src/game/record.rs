@@ -0,0 +1,143 @@
+use crate::{error, generator::daily, scene::level::Level};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+pub struct RecordPlugin;
+
+impl Plugin for RecordPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LevelRecords::load());
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const RECORDS_DIRECTORY: &str = "alien-architect";
+
+const RECORDS_FILE: &str = "records.ron";
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelRecord {
+    pub score: usize,
+    pub turns: usize,
+    // Date the record was last improved on
+    pub date: String,
+}
+
+// Best results keyed by the checksum of the level, so edited levels start over
+#[derive(Debug, Default, Resource, Serialize, Deserialize)]
+pub struct LevelRecords {
+    records: BTreeMap<String, LevelRecord>,
+    // Checksums of the campaign levels, read once and refreshed when a level file is reloaded
+    #[serde(skip)]
+    checksums: Vec<Option<u64>>,
+}
+
+impl LevelRecords {
+    fn load() -> Self {
+        let mut records = Self::read();
+        records.checksums = Level::checksums();
+
+        return records;
+    }
+
+    fn read() -> Self {
+        let path = match records_path() {
+            Some(path) => path,
+            None => return Self::default(),
+        };
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        return match error::read_ron(&path.to_string_lossy()) {
+            Ok(records) => records,
+            Err(e) => {
+                error!("{}", e);
+                Self::default()
+            }
+        };
+    }
+
+    fn save(&self) {
+        let path = match records_path() {
+            Some(path) => path,
+            None => return,
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                error!("Failed to create `{}`: {}", parent.display(), e);
+                return;
+            }
+        }
+
+        if let Err(e) = error::write_ron(&path.to_string_lossy(), self) {
+            error!("{}", e);
+        }
+    }
+
+    pub fn get(&self, checksum: u64) -> Option<&LevelRecord> {
+        return self.records.get(&key(checksum));
+    }
+
+    pub fn checksums(&self) -> &[Option<u64>] {
+        return &self.checksums;
+    }
+
+    pub fn update_checksum(&mut self, level: usize, checksum: u64) {
+        if let Some(current) = self.checksums.get_mut(level) {
+            *current = Some(checksum);
+        }
+    }
+
+    // Keeps the best score and the fewest turns separately, returns true if either improved
+    pub fn record(&mut self, checksum: u64, score: usize, turns: usize) -> bool {
+        let (year, month, day) = daily::today();
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+
+        let record = match self.records.get_mut(&key(checksum)) {
+            Some(record) => record,
+            None => {
+                self.records
+                    .insert(key(checksum), LevelRecord { score, turns, date });
+                self.save();
+
+                return true;
+            }
+        };
+
+        if score <= record.score && turns >= record.turns {
+            return false;
+        }
+
+        record.score = record.score.max(score);
+        record.turns = record.turns.min(turns);
+        record.date = date;
+
+        self.save();
+
+        return true;
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn key(checksum: u64) -> String {
+    return format!("{:016X}", checksum);
+}
+
+fn records_path() -> Option<PathBuf> {
+    return dirs::data_dir().map(|dir| dir.join(RECORDS_DIRECTORY).join(RECORDS_FILE));
+}
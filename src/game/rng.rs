@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+/************************************************************
+ * - Types
+ */
+
+// Source of every random decision actors make, reseeded to make a level play out the same way.
+// ChaCha8 gives the same sequence on every platform and rand version, unlike `StdRng`.
+#[derive(Debug, Resource)]
+pub struct GameRng(ChaCha8Rng);
+
+impl GameRng {
+    pub fn new() -> Self {
+        Self(ChaCha8Rng::from_entropy())
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.0 = ChaCha8Rng::seed_from_u64(seed);
+    }
+
+    pub fn rng(&mut self) -> &mut ChaCha8Rng {
+        return &mut self.0;
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    animation::{Animate, AnimationMode},
+    animation::{Animate, AnimationMode, PlayAnimationEvent, ANIMATION_DEFAULT_CLIP},
+    asset::GameAssetServer,
+    camera::CameraEffectEvent,
+    error::GameResult,
     game::warn::SpawnWarningEvent,
     object::{self, Object, ObjectSelectEvent, Selectable},
     object::{
@@ -8,12 +11,14 @@ use crate::{
         turn::ObjectsActTurnsEvent,
         ObjectID,
     },
-    render::{RenderLayer, RENDER_LAYER},
+    render::{RenderLayer, RenderOrder, RENDER_LAYER},
     scene::level::{Score, TurnCounter},
+    settings::Settings,
     state::{
         transition::{SceneTransitionEvent, TransitionEffect},
         AppState,
     },
+    tween::{self, Ease, Tween},
     world::tile::{TileState, TileStateChangeEvent},
     world::{grid::Grid, World},
 };
@@ -25,9 +30,11 @@ pub struct UFOPlugin;
 
 impl Plugin for UFOPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<UFODropEvent>()
+        app.register_type::<UFO>()
+            .add_event::<UFODropEvent>()
             .add_event::<UFOLiftEvent>()
             .add_event::<UFOCancelEvent>()
+            .add_event::<UFORotateEvent>()
             .add_systems(
                 Update,
                 (control_ufo, ufo_carry_object).run_if(
@@ -39,8 +46,10 @@ impl Plugin for UFOPlugin {
                 (
                     handle_ufo_lift_event,
                     handle_ufo_cancel_event,
+                    handle_ufo_rotate_event,
                     handle_ufo_drop_event,
                 )
+                    .before(tween::TweenSystem)
                     .run_if(
                         in_state(AppState::Game).and_then(in_state(GameState::PlayerControlled)),
                     ),
@@ -52,31 +61,44 @@ impl Plugin for UFOPlugin {
  * - Constants
  */
 
-const UFO_TEXTURE_ATLAS_PATH: &str = "ufo_ss.png";
+pub const UFO_ASSET_ID: &str = "ufo";
 
-pub const UFO_TEXTURE_ATLAS_TILE: (usize, usize) = (20, 15);
-
-const UFO_TEXTURE_ATLAS_SIZE: (usize, usize) = (6, 1);
+// Strength and duration of the camera shake on an invalid drop
+const UFO_INVALID_DROP_SHAKE: (f32, f32) = (2.0, 0.25);
 
 const UFO_SPRITE_OFFSET: (i32, i32) = (5, 4 + 26);
 
 const UFO_LIFT_MODIFIER: i32 = 8;
 
+// Carried objects are drawn over whatever occupies the cells below them
+const UFO_CARRIED_SUB_ORDER: u32 = 1;
+
 /************************************************************
  * - Types
  */
 
+// Where a dropped Object ends up, found by `check_drop`
 #[derive(Debug)]
+pub struct DropOutcome {
+    pub occupied: Vec<IVec2>,
+    // Housed Villagers and farmed Cows leave the board
+    pub self_destruct: bool,
+}
+
+#[derive(Debug, Reflect)]
 struct UFOSelection {
     entity: Entity,
     occupy_index: usize,
+    // Orientation the Object is dropped in, the Object keeps its own until then
+    orientation: usize,
 }
 
 impl UFOSelection {
-    fn new(entity: Entity, occupy_index: usize) -> Self {
+    fn new(entity: Entity, occupy_index: usize, orientation: usize) -> Self {
         Self {
             entity,
             occupy_index,
+            orientation,
         }
     }
 }
@@ -92,7 +114,7 @@ impl UFOLiftEvent {
     }
 }
 
-#[derive(Debug, Event)]
+#[derive(Debug, Default, Event)]
 pub struct UFODropEvent;
 
 impl UFODropEvent {
@@ -101,7 +123,7 @@ impl UFODropEvent {
     }
 }
 
-#[derive(Debug, Event)]
+#[derive(Debug, Default, Event)]
 pub struct UFOCancelEvent;
 
 impl UFOCancelEvent {
@@ -110,7 +132,17 @@ impl UFOCancelEvent {
     }
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Default, Event)]
+pub struct UFORotateEvent;
+
+impl UFORotateEvent {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
 pub struct UFO {
     pub position: IVec2,
     pub offset: IVec2,
@@ -121,31 +153,18 @@ impl UFO {
     pub fn new(
         position: IVec2,
         grid: &Grid,
-        asset_server: &AssetServer,
+        gas: &GameAssetServer,
         commands: &mut Commands,
-        texture_atlases: &mut Assets<TextureAtlas>,
         events: &mut EventWriter<TileStateChangeEvent>,
-    ) -> Entity {
+    ) -> GameResult<Entity> {
         let world_position: Vec2 =
             grid.cell_to_world(UVec2::new(position.x as u32, position.y as u32));
 
-        events.send(TileStateChangeEvent::new(position, TileState::Selected));
+        let asset = gas.atlas(UFO_ASSET_ID)?;
 
-        let image = asset_server.load(UFO_TEXTURE_ATLAS_PATH);
-
-        let texture_atlas = TextureAtlas::from_grid(
-            image,
-            Vec2::new(
-                UFO_TEXTURE_ATLAS_TILE.0 as f32,
-                UFO_TEXTURE_ATLAS_TILE.1 as f32,
-            ),
-            UFO_TEXTURE_ATLAS_SIZE.0,
-            UFO_TEXTURE_ATLAS_SIZE.1,
-            None,
-            None,
-        );
+        events.send(TileStateChangeEvent::new(position, TileState::Selected));
 
-        return commands
+        return Ok(commands
             .spawn((
                 SpriteSheetBundle {
                     transform: Transform::from_xyz(
@@ -153,7 +172,7 @@ impl UFO {
                         world_position.y + UFO_SPRITE_OFFSET.1 as f32,
                         RENDER_LAYER[RenderLayer::UFO as usize] as f32,
                     ),
-                    texture_atlas: texture_atlases.add(texture_atlas),
+                    texture_atlas: asset.handle.clone(),
                     sprite: TextureAtlasSprite {
                         index: 0,
                         anchor: Anchor::BottomLeft,
@@ -166,14 +185,32 @@ impl UFO {
                     offset: IVec2::new(UFO_SPRITE_OFFSET.0, UFO_SPRITE_OFFSET.1),
                     selected: None,
                 },
-                Animate::new(
-                    UFO_TEXTURE_ATLAS_SIZE.0 * UFO_TEXTURE_ATLAS_SIZE.1,
-                    0.2,
-                    AnimationMode::Loop,
-                ),
+                Animate::new(asset.desc.frame_count(), 0.2, AnimationMode::Loop),
+                Tween::new(tween::TWEEN_UFO_DURATION, Ease::QuadOut),
                 Name::new("UFO"),
             ))
-            .id();
+            .id());
+    }
+
+    // Object being carried, if any
+    pub fn carrying(&self) -> Option<Entity> {
+        return self.selected.as_ref().map(|selection| selection.entity);
+    }
+
+    // Carried Object and the orientation it would be dropped in
+    pub fn carried(&self) -> Option<(Entity, usize)> {
+        return self
+            .selected
+            .as_ref()
+            .map(|selection| (selection.entity, selection.orientation));
+    }
+
+    // Cell the carried Object would be placed by if it was dropped now
+    pub fn drop_target(&self, asset: &ObjectAsset) -> Option<IVec2> {
+        return self
+            .selected
+            .as_ref()
+            .map(|selection| calculate_object_poition(self, selection, asset));
     }
 }
 
@@ -188,9 +225,11 @@ pub fn control_ufo(
     mut lift_event_writer: EventWriter<UFOLiftEvent>,
     mut drop_event_writer: EventWriter<UFODropEvent>,
     mut canc_event_writer: EventWriter<UFOCancelEvent>,
+    mut rota_event_writer: EventWriter<UFORotateEvent>,
     mut trns_event_writer: EventWriter<SceneTransitionEvent>,
     mut game_state: ResMut<NextState<GameState>>,
     mut score: ResMut<Score>,
+    settings: Res<Settings>,
     grid: Res<Grid>,
     keys: Res<Input<KeyCode>>,
 ) {
@@ -198,24 +237,33 @@ pub fn control_ufo(
     let (mut ufo, mut transform) = match ufo_query.get_single_mut() {
         Ok(tuple) => tuple,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
 
+    let input = &settings.input;
+
+    // Settings
+    if keys.just_pressed(input.settings) {
+        game_state.set(GameState::Settings);
+        return;
+    }
+
     let y_mod = ufo.position.y % 2;
 
     let mut moved = false;
     let mut position = ufo.position;
 
     // Vertical
-    if keys.just_pressed(KeyCode::D) {
+    if keys.just_pressed(input.right) {
         position.x += 0 + y_mod;
         position.y += -1;
 
         moved = true;
     }
-    if keys.just_pressed(KeyCode::A) {
+    if keys.just_pressed(input.left) {
         position.x += -1 + y_mod;
         position.y += 1;
 
@@ -223,13 +271,13 @@ pub fn control_ufo(
     }
 
     // Horizontal
-    if keys.just_pressed(KeyCode::W) {
+    if keys.just_pressed(input.up) {
         position.x += 0 + y_mod;
         position.y += 1;
 
         moved = true;
     }
-    if keys.just_pressed(KeyCode::S) {
+    if keys.just_pressed(input.down) {
         position.x += -1 + y_mod;
         position.y += -1;
 
@@ -237,7 +285,7 @@ pub fn control_ufo(
     }
 
     // Lift & Drop
-    if keys.just_pressed(KeyCode::H) {
+    if keys.just_pressed(input.lift) {
         if ufo.selected.is_none() {
             objc_event_writer.send(ObjectSelectEvent::new(ufo.position));
 
@@ -248,14 +296,17 @@ pub fn control_ufo(
     }
 
     // Cancel
-    if keys.just_pressed(KeyCode::J) {
-        if !ufo.selected.is_none() {
-            canc_event_writer.send(UFOCancelEvent::new());
-        }
+    if keys.just_pressed(input.cancel) && ufo.selected.is_some() {
+        canc_event_writer.send(UFOCancelEvent::new());
+    }
+
+    // Rotate
+    if keys.just_pressed(input.rotate) && ufo.selected.is_some() {
+        rota_event_writer.send(UFORotateEvent::new());
     }
 
     // Restart
-    if keys.just_pressed(KeyCode::Return) {
+    if keys.just_pressed(input.restart) {
         trns_event_writer.send(SceneTransitionEvent::new(
             TransitionEffect::Wipe,
             AppState::Game,
@@ -263,7 +314,7 @@ pub fn control_ufo(
 
         game_state.set(GameState::Paused);
 
-        score.current = score.previous;
+        score.reset();
         return;
     }
 
@@ -292,6 +343,13 @@ fn ufo_move(target: IVec2, ufo: &mut UFO, transform: &mut Transform, grid: &Grid
         return false;
     }
 
+    ufo_place(target, ufo, transform, grid);
+
+    return true;
+}
+
+// Moves the UFO without checking the target, callers validate it themselves
+pub fn ufo_place(target: IVec2, ufo: &mut UFO, transform: &mut Transform, grid: &Grid) {
     ufo.position.x = target.x;
     ufo.position.y = target.y;
 
@@ -300,12 +358,10 @@ fn ufo_move(target: IVec2, ufo: &mut UFO, transform: &mut Transform, grid: &Grid
 
     transform.translation.x = world_position.x + ufo.offset.x as f32;
     transform.translation.y = world_position.y + ufo.offset.y as f32;
-
-    return true;
 }
 
 fn ufo_carry_object(
-    mut obj_query: Query<(Entity, &mut Transform, &Object), With<Selectable>>,
+    mut obj_query: Query<(Entity, &mut Transform, &mut RenderOrder, &Object), With<Selectable>>,
     ufo_query: Query<&UFO>,
     oas: Res<ObjectAssetServer>,
     grid: Res<Grid>,
@@ -314,7 +370,8 @@ fn ufo_carry_object(
     let ufo = match ufo_query.get_single() {
         Ok(tuple) => tuple,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
@@ -324,12 +381,18 @@ fn ufo_carry_object(
         None => return,
     };
 
-    for (entity, mut transform, obj) in &mut obj_query {
+    for (entity, mut transform, mut order, obj) in &mut obj_query {
         if entity != selection.entity {
             continue;
         }
 
-        let asset = oas.get(obj.id);
+        let asset = match oas.get(obj.id) {
+            Ok(asset) => asset,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
         let target = calculate_object_poition(ufo, selection, asset);
 
         if (target.x < 0 || target.x >= grid.size.0 as i32)
@@ -339,11 +402,16 @@ fn ufo_carry_object(
         }
 
         let world_position = grid.cell_to_world(UVec2::new(target.x as u32, target.y as u32));
-        let order = grid.cell_order(UVec2::new(target.x as u32, target.y as u32));
+        let offset = asset.conf.offset(selection.orientation);
 
-        transform.translation.x = world_position.x + obj.offset.x as f32;
-        transform.translation.y = world_position.y + obj.offset.y as f32 + UFO_LIFT_MODIFIER as f32;
-        transform.translation.z = (RENDER_LAYER[RenderLayer::Entity as usize] + order) as f32;
+        transform.translation.x = world_position.x + offset.x as f32;
+        transform.translation.y = world_position.y + offset.y as f32 + UFO_LIFT_MODIFIER as f32;
+
+        order.set_cells(&object::occupied_cells(
+            UVec2::new(target.x as u32, target.y as u32),
+            asset.conf.occupy(selection.orientation),
+        ));
+        order.sub = UFO_CARRIED_SUB_ORDER;
     }
 }
 
@@ -352,6 +420,7 @@ fn handle_ufo_lift_event(
     mut obj_query: Query<(Entity, &Object), With<Selectable>>,
     mut warn_event_writer: EventWriter<SpawnWarningEvent>,
     mut tile_event_writer: EventWriter<TileStateChangeEvent>,
+    mut anim_event_writer: EventWriter<PlayAnimationEvent>,
     mut event_reader: EventReader<UFOLiftEvent>,
     oas: Res<ObjectAssetServer>,
     world: Res<World>,
@@ -359,7 +428,7 @@ fn handle_ufo_lift_event(
 ) {
     // Validate ER
     if event_reader.len() > 1 {
-        panic!("Encountered unhandled UFOLiftEvent.");
+        warn!("Encountered unhandled UFOLiftEvent.");
     }
     let event = match event_reader.iter().next() {
         Some(event) => event,
@@ -370,7 +439,8 @@ fn handle_ufo_lift_event(
     let mut ufo = match ufo_query.get_single_mut() {
         Ok(tuple) => tuple,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
@@ -394,12 +464,26 @@ fn handle_ufo_lift_event(
         tile_event_writer.send(TileStateChangeEvent::new(ufo.position, TileState::Default));
 
         // Set TileState to Selected for every valid position
-        let valid = object::find_valid_cells(entity, obj.id, event.position, &oas, &world, &grid);
+        let valid = object::find_valid_cells(
+            entity,
+            obj.id,
+            event.position,
+            obj.orientation,
+            &oas,
+            &world,
+            &grid,
+        );
         for cell in valid {
             tile_event_writer.send(TileStateChangeEvent::new(cell, TileState::Selected));
         }
 
-        ufo.selected = Some(UFOSelection::new(entity, occupy_index as usize));
+        ufo.selected = Some(UFOSelection::new(
+            entity,
+            occupy_index as usize,
+            obj.orientation,
+        ));
+
+        anim_event_writer.send(PlayAnimationEvent::new(entity, "carried"));
         break;
     }
 
@@ -410,8 +494,9 @@ fn handle_ufo_lift_event(
 
 fn handle_ufo_cancel_event(
     mut ufo_query: Query<&mut UFO>,
-    mut obj_query: Query<(Entity, &Object, &mut Transform), With<Selectable>>,
+    mut obj_query: Query<(Entity, &Object, &mut Transform, &mut RenderOrder), With<Selectable>>,
     mut event_writer: EventWriter<TileStateChangeEvent>,
+    mut anim_event_writer: EventWriter<PlayAnimationEvent>,
     mut event_reader: EventReader<UFOCancelEvent>,
     oas: Res<ObjectAssetServer>,
     world: Res<World>,
@@ -419,7 +504,7 @@ fn handle_ufo_cancel_event(
 ) {
     // Validate ER
     if event_reader.len() > 1 {
-        panic!("Encountered unhandled UFOLiftEvent.");
+        warn!("Encountered unhandled UFOCancelEvent.");
     }
     if event_reader.is_empty() {
         return;
@@ -431,18 +516,22 @@ fn handle_ufo_cancel_event(
     let mut ufo = match ufo_query.get_single_mut() {
         Ok(tuple) => tuple,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
 
     let selection = match &ufo.selected {
         Some(selection) => selection,
-        None => panic!("UFO haven't selected any object but, UFODropEvent is called."),
+        None => {
+            error!("UFO haven't selected any object but, UFOCancelEvent is called.");
+            return;
+        }
     };
 
     // Loop trough every object and reposition the selected one
-    for (entity, object, mut transform) in &mut obj_query {
+    for (entity, object, mut transform, mut order) in &mut obj_query {
         if entity != selection.entity {
             continue;
         }
@@ -450,7 +539,15 @@ fn handle_ufo_cancel_event(
         let position = object.occupied[0];
 
         // Change tile states
-        let valid = find_valid_cells(entity, object.id, position, &oas, &world, &grid);
+        let valid = find_valid_cells(
+            entity,
+            object.id,
+            position,
+            selection.orientation,
+            &oas,
+            &world,
+            &grid,
+        );
         for cell in valid {
             if cell == ufo.position {
                 continue;
@@ -460,11 +557,14 @@ fn handle_ufo_cancel_event(
         }
 
         let world_position = grid.cell_to_world(UVec2::new(position.x as u32, position.y as u32));
-        let order = grid.cell_order(UVec2::new(position.x as u32, position.y as u32));
 
         transform.translation.x = world_position.x + object.offset.x as f32;
         transform.translation.y = world_position.y + object.offset.y as f32;
-        transform.translation.z = (RENDER_LAYER[RenderLayer::Entity as usize] + order) as f32;
+
+        order.set_cells(&object.occupied);
+        order.sub = 0;
+
+        anim_event_writer.send(PlayAnimationEvent::new(entity, ANIMATION_DEFAULT_CLIP));
 
         ufo.selected = None;
 
@@ -472,13 +572,107 @@ fn handle_ufo_cancel_event(
     }
 }
 
+fn handle_ufo_rotate_event(
+    mut ufo_query: Query<&mut UFO>,
+    obj_query: Query<(Entity, &Object), With<Selectable>>,
+    mut warn_event_writer: EventWriter<SpawnWarningEvent>,
+    mut tile_event_writer: EventWriter<TileStateChangeEvent>,
+    mut event_reader: EventReader<UFORotateEvent>,
+    oas: Res<ObjectAssetServer>,
+    world: Res<World>,
+    grid: Res<Grid>,
+) {
+    if event_reader.is_empty() {
+        return;
+    }
+
+    event_reader.clear();
+
+    // Get UFO
+    let mut ufo = match ufo_query.get_single_mut() {
+        Ok(tuple) => tuple,
+        Err(QuerySingleError::MultipleEntities(_)) => {
+            error!("Multiple UFOs are present in the scene.");
+            return;
+        }
+        Err(QuerySingleError::NoEntities(_)) => return,
+    };
+
+    let selection = match &mut ufo.selected {
+        Some(selection) => selection,
+        None => {
+            error!("UFO haven't selected any object but, UFORotateEvent is called.");
+            return;
+        }
+    };
+
+    let (entity, object) = match obj_query.get(selection.entity) {
+        Ok(tuple) => tuple,
+        Err(_) => return,
+    };
+
+    let asset = match oas.get(object.id) {
+        Ok(asset) => asset,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
+    };
+
+    // Single footprint Objects can't be rotated
+    let count = asset.conf.orientation_count();
+    if count < 2 {
+        warn_event_writer.send(SpawnWarningEvent::new());
+        return;
+    }
+
+    // Swap the selected tiles for the ones of the next orientation
+    let position = object.occupied[0];
+    let previous = find_valid_cells(
+        entity,
+        object.id,
+        position,
+        selection.orientation,
+        &oas,
+        &world,
+        &grid,
+    );
+    for cell in previous {
+        tile_event_writer.send(TileStateChangeEvent::new(cell, TileState::Default));
+    }
+
+    selection.orientation = (selection.orientation + 1) % count;
+
+    // The UFO keeps holding the same cell of the footprint when there is one
+    let cells = asset.conf.occupy(selection.orientation).len();
+    if selection.occupy_index >= cells {
+        selection.occupy_index = 0;
+    }
+
+    let valid = find_valid_cells(
+        entity,
+        object.id,
+        position,
+        selection.orientation,
+        &oas,
+        &world,
+        &grid,
+    );
+    for cell in valid {
+        tile_event_writer.send(TileStateChangeEvent::new(cell, TileState::Selected));
+    }
+}
+
 fn handle_ufo_drop_event(
     mut ufo_query: Query<&mut UFO>,
-    mut obj_query: Query<(Entity, &mut Object, &mut Transform), With<Selectable>>,
+    mut obj_query: Query<(Entity, &mut Object, &mut Transform, &mut RenderOrder), With<Selectable>>,
     mut turn_event_writer: EventWriter<ObjectsActTurnsEvent>,
     mut warn_event_writer: EventWriter<SpawnWarningEvent>,
     mut tile_event_writer: EventWriter<TileStateChangeEvent>,
+    mut anim_event_writer: EventWriter<PlayAnimationEvent>,
+    mut camera_event_writer: EventWriter<CameraEffectEvent>,
     mut event_reader: EventReader<UFODropEvent>,
+    mut game_state: ResMut<NextState<GameState>>,
     mut world: ResMut<World>,
     mut turn_counter: ResMut<TurnCounter>,
     oas: Res<ObjectAssetServer>,
@@ -486,7 +680,7 @@ fn handle_ufo_drop_event(
 ) {
     // Validate ER
     if event_reader.len() > 1 {
-        panic!("Encountered unhandled UFOLiftEvent.");
+        warn!("Encountered unhandled UFODropEvent.");
     }
     if event_reader.is_empty() {
         return;
@@ -498,53 +692,61 @@ fn handle_ufo_drop_event(
     let mut ufo = match ufo_query.get_single_mut() {
         Ok(tuple) => tuple,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
 
     let selection = match &ufo.selected {
         Some(selection) => selection,
-        None => panic!("UFO haven't selected any object but, UFODropEvent is called."),
+        None => {
+            error!("UFO haven't selected any object but, UFODropEvent is called.");
+            return;
+        }
     };
 
-    for (entity, mut obj, mut transform) in &mut obj_query {
+    for (entity, mut obj, mut transform, mut order) in &mut obj_query {
         if entity != selection.entity {
             continue;
         }
 
-        let asset = oas.get(obj.id);
-        let valid = object::find_valid_cells(entity, obj.id, obj.occupied[0], &oas, &world, &grid);
+        let asset = match oas.get(obj.id) {
+            Ok(asset) => asset,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+        let orientation = selection.orientation;
 
         // Calculate Object's position
         let target = calculate_object_poition(&ufo, selection, asset);
-        let y_mod = target.y % 2;
-
-        // Calculate Object's possible new occupied territory
-        let mut occupied = vec![];
-        for (i, offset) in asset.conf.occupy.iter().enumerate() {
-            occupied.push(IVec2::new(
-                target.x as i32
-                    + (if i == 0 {
-                        offset.x
-                    } else {
-                        offset.x + y_mod as i32
-                    }),
-                target.y as i32 + offset.y,
-            ));
-        }
 
-        // Validate Object's new position
-        for cell in &occupied {
-            if !valid.contains(cell) {
+        let drop = match check_drop(entity, &obj, target, orientation, &oas, &world, &grid) {
+            Some(drop) => drop,
+            None => {
                 // If position is not valid send a SpawnWarningEvent
                 warn_event_writer.send(SpawnWarningEvent::new());
+                camera_event_writer.send(CameraEffectEvent::shake(
+                    UFO_INVALID_DROP_SHAKE.0,
+                    UFO_INVALID_DROP_SHAKE.1,
+                ));
 
                 return;
             }
-        }
+        };
 
         // Clear TileState of currently Selected tiles, except the tile UFO is hovering.
+        let valid = object::find_valid_cells(
+            entity,
+            obj.id,
+            obj.occupied[0],
+            orientation,
+            &oas,
+            &world,
+            &grid,
+        );
         for cell in valid {
             if cell == ufo.position {
                 continue;
@@ -553,56 +755,6 @@ fn handle_ufo_drop_event(
             tile_event_writer.send(TileStateChangeEvent::new(cell, TileState::Default));
         }
 
-        // Check if Object is Cow or Villager
-        let index = ((occupied[0].y * grid.size.0 as i32) + occupied[0].x) as usize;
-
-        let mut self_destruct = false;
-
-        if occupied[0] != obj.occupied[0] {
-            match obj.id {
-                ObjectID::Cow => {
-                    match world.objects[index] {
-                        Some((_, id)) => match id {
-                            ObjectID::Farm => {
-                                self_destruct = true;
-                            }
-                            _ => {
-                                // If position is not valid send a SpawnWarningEvent
-                                warn_event_writer.send(SpawnWarningEvent::new());
-
-                                return;
-                            }
-                        },
-                        None => {}
-                    }
-                }
-                ObjectID::Villager => {
-                    match world.objects[index] {
-                        Some((_, id)) => match id {
-                            ObjectID::House | ObjectID::BigHouse => {
-                                self_destruct = true;
-                            }
-                            _ => {
-                                // If position is not valid send a SpawnWarningEvent
-                                warn_event_writer.send(SpawnWarningEvent::new());
-
-                                return;
-                            }
-                        },
-                        None => {}
-                    }
-                }
-                _ => {
-                    if !world.objects[index].is_none() {
-                        // If position is not valid send a SpawnWarningEvent
-                        warn_event_writer.send(SpawnWarningEvent::new());
-
-                        return;
-                    }
-                }
-            }
-        }
-
         // Set the World data to None for Object's previous position.
         for cell in &obj.occupied {
             let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
@@ -613,14 +765,17 @@ fn handle_ufo_drop_event(
         // Increment the TurnCounter
         turn_counter.turn += 1;
 
-        // Send an event to end the current turn
+        // Send an event to end the current turn, Objects act before the player gets control back
         turn_event_writer.send(ObjectsActTurnsEvent::new());
+        game_state.set(GameState::ObjectControlled);
 
         // Set Object's new position
-        obj.occupied = occupied;
+        obj.occupied = drop.occupied;
+        obj.orientation = orientation;
+        obj.offset = asset.conf.offset(orientation);
 
         // If Object won't detroy itself modify the World data
-        if !self_destruct {
+        if !drop.self_destruct {
             // Set the World data to Some(obj) for the new position
             for cell in &obj.occupied {
                 let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
@@ -631,11 +786,14 @@ fn handle_ufo_drop_event(
 
         // Set Object's transform to new position
         let world_position = grid.cell_to_world(UVec2::new(target.x as u32, target.y as u32));
-        let order = grid.cell_order(UVec2::new(target.x as u32, target.y as u32));
 
         transform.translation.x = world_position.x + obj.offset.x as f32;
         transform.translation.y = world_position.y + obj.offset.y as f32;
-        transform.translation.z = (RENDER_LAYER[RenderLayer::Entity as usize] + order) as f32;
+
+        order.set_cells(&obj.occupied);
+        order.sub = 0;
+
+        anim_event_writer.send(PlayAnimationEvent::new(entity, ANIMATION_DEFAULT_CLIP));
 
         // Set UFO's selected to None
         ufo.selected = None;
@@ -649,13 +807,101 @@ fn handle_ufo_drop_event(
  * - Helper Functions
  */
 
+// Cells `object` covers when dropped in `orientation` with its first cell at `target`, None when the drop is refused.
+// Villagers and Cows leave the board when housed or farmed.
+pub fn check_drop(
+    entity: Entity,
+    object: &Object,
+    target: IVec2,
+    orientation: usize,
+    oas: &ObjectAssetServer,
+    world: &World,
+    grid: &Grid,
+) -> Option<DropOutcome> {
+    if !grid.contains(target) {
+        return None;
+    }
+
+    let asset = oas.get(object.id).ok()?;
+    let valid = object::find_valid_cells(
+        entity,
+        object.id,
+        object.occupied[0],
+        orientation,
+        oas,
+        world,
+        grid,
+    );
+
+    let occupied = object::occupied_cells(
+        UVec2::new(target.x as u32, target.y as u32),
+        asset.conf.occupy(orientation),
+    );
+    if occupied.iter().any(|cell| !valid.contains(cell)) {
+        return None;
+    }
+
+    let index = ((occupied[0].y * grid.size.0 as i32) + occupied[0].x) as usize;
+
+    let mut self_destruct = false;
+
+    if occupied[0] != object.occupied[0] {
+        match (object.id, world.objects[index]) {
+            (_, None) => {}
+            (ObjectID::Cow, Some((_, ObjectID::Farm))) => self_destruct = true,
+            (ObjectID::Villager, Some((_, ObjectID::House | ObjectID::BigHouse))) => {
+                self_destruct = true
+            }
+            _ => return None,
+        }
+    }
+
+    return Some(DropOutcome {
+        occupied,
+        self_destruct,
+    });
+}
+
+// World after `object` is dropped, None when the drop is refused
+pub fn simulate_drop(
+    entity: Entity,
+    object: &Object,
+    target: IVec2,
+    orientation: usize,
+    oas: &ObjectAssetServer,
+    world: &World,
+    grid: &Grid,
+) -> Option<World> {
+    let drop = check_drop(entity, object, target, orientation, oas, world, grid)?;
+
+    let mut result = world.clone();
+
+    for cell in &object.occupied {
+        let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
+
+        result.objects[index] = None;
+    }
+
+    if !drop.self_destruct {
+        for cell in &drop.occupied {
+            let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
+
+            result.objects[index] = Some((entity, object.id));
+        }
+    }
+
+    return Some(result);
+}
+
 fn calculate_object_poition(ufo: &UFO, selection: &UFOSelection, asset: &ObjectAsset) -> IVec2 {
     let y_mod = (ufo.position.y + 1) % 2;
 
+    let occupy = asset.conf.occupy(selection.orientation);
+
     let diff = if selection.occupy_index == 0 {
-        asset.conf.occupy[selection.occupy_index]
+        occupy[selection.occupy_index]
     } else {
-        asset.conf.occupy[selection.occupy_index] + IVec2::new(y_mod, 0)
+        occupy[selection.occupy_index] + IVec2::new(y_mod, 0)
     };
 
     return ufo.position - diff;
@@ -1,5 +1,8 @@
-use super::ufo::{control_ufo, UFO, UFO_TEXTURE_ATLAS_TILE};
+use super::ufo::{control_ufo, UFO, UFO_ASSET_ID};
+use crate::asset::GameAssetServer;
+use crate::error::GameResult;
 use crate::render::{RenderLayer, RENDER_LAYER};
+use crate::scene::error::GameErrorEvent;
 use bevy::ecs::query::QuerySingleError;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
@@ -25,9 +28,7 @@ impl Plugin for WarningPlugin {
  * - Constants
  */
 
-const WARNING_IMAGE_PATH: &str = "exclamation_mark.png";
-
-const WARNING_IMAGE_SIZE: (usize, usize) = (6, 10);
+const WARNING_ASSET_ID: &str = "warning";
 
 const WARNING_LIFESPAN: f32 = 0.6;
 
@@ -52,10 +53,12 @@ pub struct Warning {
 impl Warning {
     pub fn new(
         commands: &mut Commands,
-        asset_server: &AssetServer,
+        gas: &GameAssetServer,
         ufo_transform: &Transform,
-    ) -> Entity {
-        commands
+    ) -> GameResult<Entity> {
+        let asset = gas.texture(WARNING_ASSET_ID)?;
+
+        return Ok(commands
             .spawn((
                 SpriteBundle {
                     transform: Transform::from_xyz(
@@ -63,7 +66,7 @@ impl Warning {
                         ufo_transform.translation.y,
                         RENDER_LAYER[RenderLayer::UI as usize] as f32,
                     ),
-                    texture: asset_server.load(WARNING_IMAGE_PATH),
+                    texture: asset.handle.clone(),
                     sprite: Sprite {
                         anchor: Anchor::BottomLeft,
                         ..Default::default()
@@ -75,7 +78,7 @@ impl Warning {
                 },
                 Name::new("Warning Indicator"),
             ))
-            .id()
+            .id());
     }
 }
 
@@ -86,20 +89,27 @@ impl Warning {
 fn update_warning_indicator_positions(
     mut ind_query: Query<&mut Transform, (With<Warning>, Without<UFO>)>,
     ufo_query: Query<&Transform, With<UFO>>,
+    gas: Res<GameAssetServer>,
 ) {
     let ufo = match ufo_query.get_single() {
         Ok(ufo) => ufo,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple UFOs are present in the scene.")
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
 
+    // Both were looked up when spawning, a missing one was reported there
+    let (ufo_size, warning_size) = match (gas.atlas(UFO_ASSET_ID), gas.texture(WARNING_ASSET_ID)) {
+        (Ok(ufo), Ok(warning)) => (ufo.desc.tile.as_vec2(), warning.desc.size.as_vec2()),
+        _ => return,
+    };
+
     for mut indicator in &mut ind_query {
-        indicator.translation.x =
-            ufo.translation.x + ((UFO_TEXTURE_ATLAS_TILE.0 - WARNING_IMAGE_SIZE.0) / 2) as f32;
-        indicator.translation.y =
-            ufo.translation.y + (UFO_TEXTURE_ATLAS_TILE.1 + (WARNING_IMAGE_SIZE.1 / 2)) as f32;
+        // A warning wider than the UFO sticks out on both sides
+        indicator.translation.x = ufo.translation.x + ((ufo_size.x - warning_size.x) / 2.0).floor();
+        indicator.translation.y = ufo.translation.y + ufo_size.y + (warning_size.y / 2.0).floor();
     }
 }
 
@@ -120,26 +130,32 @@ fn update_warning_indicator_lifetimes(
 fn handle_spawn_warning_event(
     mut commands: Commands,
     mut event_reader: EventReader<SpawnWarningEvent>,
+    mut error_writer: EventWriter<GameErrorEvent>,
     ind_query: Query<Entity, (With<Warning>, Without<UFO>)>,
     ufo_query: Query<&Transform, With<UFO>>,
-    asset_server: Res<AssetServer>,
+    gas: Res<GameAssetServer>,
 ) {
-    for _event in &mut event_reader {
-        // Get UFO
-        let ufo = match ufo_query.get_single() {
-            Ok(ufo) => ufo,
-            Err(QuerySingleError::MultipleEntities(_)) => {
-                panic!("Multiple UFOs are present in the scene.")
-            }
-            Err(QuerySingleError::NoEntities(_)) => return,
-        };
-
-        if !ind_query.is_empty() {
-            break;
+    // Any number of requests shows a single warning
+    if event_reader.is_empty() {
+        return;
+    }
+    event_reader.clear();
+
+    // Get UFO
+    let ufo = match ufo_query.get_single() {
+        Ok(ufo) => ufo,
+        Err(QuerySingleError::MultipleEntities(_)) => {
+            error!("Multiple UFOs are present in the scene.");
+            return;
         }
+        Err(QuerySingleError::NoEntities(_)) => return,
+    };
 
-        Warning::new(&mut commands, &asset_server, ufo);
-        break;
+    if !ind_query.is_empty() {
+        return;
+    }
+
+    if let Err(e) = Warning::new(&mut commands, &gas, ufo) {
+        error_writer.send(GameErrorEvent::new(e));
     }
-    event_reader.clear();
 }
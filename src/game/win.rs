@@ -1,14 +1,35 @@
+use super::record::LevelRecords;
 use crate::{
+    asset::GameAssetServer,
+    camera::CameraEffectEvent,
+    generator::{
+        daily::{DailyChallenge, DailyResults},
+        GeneratorConf, PendingLevel, RandomLevel,
+    },
     global::window,
+    object::{asset::ObjectAssetServer, Object, ObjectID},
     render::{RenderLayer, RENDER_LAYER},
-    scene::level::{Level, Score, TurnCounter},
+    scene::{
+        editor::EditorLevel,
+        error::GameErrorEvent,
+        level::{Level, Score, ScoreEvent, ScoreReason, TurnCounter},
+    },
+    settings::Settings,
     state::{
         transition::{SceneTransitionEvent, TransitionEffect},
         AppState,
     },
-    world::tile::{TileState, TileStateChangeEvent},
+    ui::bitmap_text::BitmapText,
+    world::{
+        grid::Grid,
+        tile::{TileState, TileStateChangeEvent},
+    },
+};
+use bevy::{
+    ecs::{query::QuerySingleError, system::SystemParam},
+    prelude::*,
+    sprite::Anchor,
 };
-use bevy::{ecs::query::QuerySingleError, prelude::*, sprite::Anchor};
 
 pub struct WinPlugin;
 
@@ -31,9 +52,7 @@ impl Plugin for WinPlugin {
  * - Constants
  */
 
-const WIN_TEXTURE_PATH: &str = "ui/game/complete_overlay.png";
-
-const WIN_TEXTUER_SIZE: (usize, usize) = (160, 36);
+const WIN_ASSET_ID: &str = "ui_complete_overlay";
 
 const WIN_ANIMATION_DEFAULT_INTERVAL: f32 = 0.15;
 
@@ -41,6 +60,18 @@ const WIN_ANIMATION_TRANSITION_TIME: f32 = 2.0;
 
 const MAXIMUM_TURN_POINT: i32 = 10;
 
+const WIN_TALLY_TIME: f32 = 4.0;
+
+const WIN_TALLY_MARGIN: f32 = 8.0;
+
+const WIN_BEST_LABEL: &str = "New best!";
+
+// How long the camera leans towards the King once the path is found
+const WIN_CAMERA_PAN_TIME: f32 = 1.2;
+
+// Amount and duration of the zoom punch as the path is found
+const WIN_CAMERA_PUNCH: (f32, f32) = (0.06, 0.3);
+
 /************************************************************
  * - Types
  */
@@ -61,6 +92,11 @@ struct WinAnimation {
     path: Vec<IVec2>,
     tile_timer: Timer,
     trns_timer: Timer,
+    // Started once the score tally is shown, the transition follows when it finishes
+    tally_timer: Option<Timer>,
+    // Level's record was improved, known once the overlay is shown
+    best: bool,
+    completed: bool,
 }
 
 impl WinAnimation {
@@ -71,11 +107,88 @@ impl WinAnimation {
                 (interval * path.len() as f32) + WIN_ANIMATION_TRANSITION_TIME,
                 TimerMode::Once,
             ),
+            tally_timer: None,
+            best: false,
+            completed: false,
             path,
         }
     }
 }
 
+// Decides where a completed level leads to, depending on how it was started
+#[derive(SystemParam)]
+struct LevelCompletion<'w, 's> {
+    commands: Commands<'w, 's>,
+    level: ResMut<'w, Level>,
+    editor: Option<Res<'w, EditorLevel>>,
+    random: Option<Res<'w, RandomLevel>>,
+    daily: Option<ResMut<'w, DailyChallenge>>,
+    results: ResMut<'w, DailyResults>,
+    records: ResMut<'w, LevelRecords>,
+    conf: Res<'w, GeneratorConf>,
+    oas: Res<'w, ObjectAssetServer>,
+    score: Res<'w, Score>,
+    turn_counter: Res<'w, TurnCounter>,
+    error_writer: EventWriter<'w, GameErrorEvent>,
+}
+
+impl LevelCompletion<'_, '_> {
+    // Returns true if the level's record was improved, test-play isn't recorded
+    fn record(&mut self) -> bool {
+        if let Some(editor) = &self.editor {
+            if editor.playing {
+                return false;
+            }
+        }
+
+        return self.records.record(
+            self.level.checksum,
+            self.score.current - self.score.previous,
+            self.turn_counter.turn,
+        );
+    }
+
+    fn next(&mut self) -> AppState {
+        // Test-play returns to the editor
+        if let Some(editor) = &self.editor {
+            if editor.playing {
+                return AppState::Editor;
+            }
+        }
+
+        // Daily challenge records its result before showing it
+        if let Some(daily) = &mut self.daily {
+            let result = daily.complete(
+                self.turn_counter.turn,
+                self.score.current - self.score.previous,
+            );
+
+            info!(
+                "Completed daily challenge {}, `{}`.",
+                daily.date, result.code
+            );
+
+            self.results.record(&daily.date, result);
+
+            return AppState::DailyResult;
+        }
+
+        // Solved random level is followed by a new one
+        if let Some(random) = &self.random {
+            self.commands.insert_resource(PendingLevel::random(
+                random.seed.wrapping_add(1),
+                &random.profile,
+                &self.conf,
+                &self.oas,
+            ));
+
+            return AppState::Generating;
+        }
+
+        return self.level.advance();
+    }
+}
+
 /************************************************************
  * - System Functions
  */
@@ -85,14 +198,20 @@ fn update_win_animation(
     mut query: Query<(Entity, &mut WinAnimation)>,
     mut trns_event_writer: EventWriter<SceneTransitionEvent>,
     mut tile_event_writer: EventWriter<TileStateChangeEvent>,
-    mut level: ResMut<Level>,
-    asset_server: Res<AssetServer>,
+    mut completion: LevelCompletion,
+    objects: Query<&Object>,
+    grid: Res<Grid>,
+    gas: Res<GameAssetServer>,
+    score: Res<Score>,
+    settings: Res<Settings>,
+    keys: Res<Input<KeyCode>>,
     time: Res<Time>,
 ) {
     let (entity, mut wa) = match query.get_single_mut() {
         Ok(wa) => wa,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Encountered multiple WinAnimation's in the scene.");
+            error!("Encountered multiple WinAnimation's in the scene.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
@@ -105,26 +224,37 @@ fn update_win_animation(
                 tile_event_writer.send(TileStateChangeEvent::new(position, TileState::Path));
             }
             None => {
-                let id = commands
-                    .spawn((
-                        SpriteBundle {
-                            transform: Transform::from_xyz(
-                                ((window::VIEWPORT_RESOLUTION.0 - WIN_TEXTUER_SIZE.0) / 2) as f32,
-                                ((window::VIEWPORT_RESOLUTION.1 - WIN_TEXTUER_SIZE.1) / 2) as f32,
-                                RENDER_LAYER[RenderLayer::UI as usize] as f32,
-                            ),
-                            texture: asset_server.load(WIN_TEXTURE_PATH),
-                            sprite: Sprite {
-                                anchor: Anchor::BottomLeft,
-                                ..Default::default()
-                            },
-                            ..Default::default()
-                        },
-                        Name::new("Win Overlay"),
-                    ))
-                    .id();
-
-                commands.entity(entity).add_child(id);
+                wa.best = completion.record();
+
+                match gas.texture(WIN_ASSET_ID) {
+                    Ok(asset) => {
+                        let id = commands
+                            .spawn((
+                                SpriteBundle {
+                                    transform: Transform::from_xyz(
+                                        ((window::VIEWPORT_RESOLUTION.0
+                                            - asset.desc.size.x as usize)
+                                            / 2) as f32,
+                                        ((window::VIEWPORT_RESOLUTION.1
+                                            - asset.desc.size.y as usize)
+                                            / 2) as f32,
+                                        RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                                    ),
+                                    texture: asset.handle.clone(),
+                                    sprite: Sprite {
+                                        anchor: Anchor::BottomLeft,
+                                        ..Default::default()
+                                    },
+                                    ..Default::default()
+                                },
+                                Name::new("Win Overlay"),
+                            ))
+                            .id();
+
+                        commands.entity(entity).add_child(id);
+                    }
+                    Err(e) => completion.error_writer.send(GameErrorEvent::new(e)),
+                }
 
                 wa.tile_timer.pause();
             }
@@ -134,19 +264,51 @@ fn update_win_animation(
     wa.trns_timer.tick(time.delta());
 
     if wa.trns_timer.just_finished() {
-        trns_event_writer.send(SceneTransitionEvent::new(
-            TransitionEffect::Fade,
-            level.next(),
-        ));
+        let id = spawn_score_tally(&score, wa.best, &mut commands);
+        commands.entity(entity).add_child(id);
+
+        wa.tally_timer = Some(Timer::from_seconds(WIN_TALLY_TIME, TimerMode::Once));
     }
+
+    if wa.completed {
+        return;
+    }
+
+    let tally_timer = match &mut wa.tally_timer {
+        Some(timer) => timer,
+        None => return,
+    };
+
+    tally_timer.tick(time.delta());
+
+    // Lift skips the rest of the tally
+    if !(tally_timer.finished() || keys.just_pressed(settings.input.lift)) {
+        return;
+    }
+
+    wa.completed = true;
+
+    // Close in on the King when it's still on the board
+    let effect = match objects.iter().find(|object| object.id == ObjectID::King) {
+        Some(king) => TransitionEffect::Iris(grid.cell_center(UVec2::new(
+            king.occupied[0].x as u32,
+            king.occupied[0].y as u32,
+        ))),
+        None => TransitionEffect::Fade,
+    };
+
+    trns_event_writer.send(SceneTransitionEvent::new(effect, completion.next()));
 }
 
 fn handle_player_win_event(
     mut commands: Commands,
     mut event_reader: EventReader<PlayerWinEvent>,
-    mut score: ResMut<Score>,
+    mut score_writer: EventWriter<ScoreEvent>,
+    mut camera_event_writer: EventWriter<CameraEffectEvent>,
     turn_counter: Res<TurnCounter>,
     query: Query<&WinAnimation>,
+    objects: Query<&Object>,
+    grid: Res<Grid>,
 ) {
     if event_reader.is_empty() {
         return;
@@ -157,7 +319,24 @@ fn handle_player_win_event(
     if query.is_empty() {
         let bonus = MAXIMUM_TURN_POINT - turn_counter.turn as i32;
 
-        score.current += if bonus <= 0 { 1 } else { bonus } as usize;
+        score_writer.send(ScoreEvent::new(
+            ScoreReason::TurnBonus,
+            if bonus <= 0 { 1 } else { bonus } as usize,
+        ));
+
+        if let Some(king) = objects.iter().find(|object| object.id == ObjectID::King) {
+            let focus = grid.cell_center(UVec2::new(
+                king.occupied[0].x as u32,
+                king.occupied[0].y as u32,
+            ));
+
+            camera_event_writer.send(CameraEffectEvent::pan(focus, WIN_CAMERA_PAN_TIME));
+        }
+
+        camera_event_writer.send(CameraEffectEvent::punch(
+            WIN_CAMERA_PUNCH.0,
+            WIN_CAMERA_PUNCH.1,
+        ));
 
         let mut path = event.path.clone();
         path.reverse();
@@ -177,3 +356,64 @@ fn unload_win_animation(mut commands: Commands, query: Query<Entity, With<WinAni
         commands.entity(entity).despawn_recursive();
     }
 }
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn spawn_score_tally(score: &Score, best: bool, commands: &mut Commands) -> Entity {
+    let mut contents = String::new();
+
+    for (reason, (count, amount)) in score.breakdown() {
+        if reason == ScoreReason::TurnBonus {
+            contents += &format!("{}: +{}\n", reason.label(), amount);
+        } else {
+            contents += &format!("{} x{}: +{}\n", reason.label(), count, amount);
+        }
+    }
+
+    contents += &format!(
+        "\nLevel: +{}\nTotal: {}",
+        score.current - score.previous,
+        score.current
+    );
+
+    if best {
+        contents += &format!("\n\n{}", WIN_BEST_LABEL);
+    }
+
+    return commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Name::new("Score Tally"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    WIN_TALLY_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - WIN_TALLY_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(&contents)
+                    .with_width(window::VIEWPORT_RESOLUTION.0 as u32 - WIN_TALLY_MARGIN as u32 * 2),
+                Name::new("Score Tally Text"),
+            ));
+        })
+        .id();
+}
@@ -0,0 +1,85 @@
+use super::{generate, solve, GeneratorConf};
+use crate::object::asset::{self, ObjectAssetServer};
+
+/************************************************************
+ * - Constants
+ */
+
+const CLI_USAGE: &str =
+    "Usage: gbjam generate [--seed <u64>] [--profile <name>] [--count <n>] [--out <dir>]";
+
+const CLI_DEFAULT_PROFILE: &str = "normal";
+
+const CLI_DEFAULT_OUT: &str = "assets/scn/generated";
+
+/************************************************************
+ * - Helper Functions
+ */
+
+// Returns true if the arguments were handled and the game shouldn't start
+pub fn run(args: &[String]) -> bool {
+    if args.first().map(String::as_str) != Some("generate") {
+        return false;
+    }
+
+    if let Err(e) = generate_levels(&args[1..]) {
+        eprintln!("{}", e);
+        eprintln!("{}", CLI_USAGE);
+
+        std::process::exit(1);
+    }
+
+    return true;
+}
+
+fn generate_levels(args: &[String]) -> Result<(), String> {
+    let mut seed: u64 = 0;
+    let mut profile = CLI_DEFAULT_PROFILE.to_string();
+    let mut count: u64 = 1;
+    let mut out = CLI_DEFAULT_OUT.to_string();
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .ok_or(format!("Missing value for `{}`.", flag))?;
+
+        match flag.as_str() {
+            "--seed" => {
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("Invalid seed `{}`.", value))?
+            }
+            "--profile" => profile = value.clone(),
+            "--count" => {
+                count = value
+                    .parse()
+                    .map_err(|_| format!("Invalid count `{}`.", value))?
+            }
+            "--out" => out = value.clone(),
+            _ => return Err(format!("Unknown argument `{}`.", flag)),
+        }
+    }
+
+    let conf = GeneratorConf::load().map_err(|e| e.to_string())?;
+    let oas = ObjectAssetServer::from_confs(asset::load_object_confs().map_err(|e| e.to_string())?);
+
+    let preset = conf.profile(&profile).map_err(|e| e.to_string())?;
+
+    std::fs::create_dir_all(&out).map_err(|e| format!("Failed to create `{}`, {}.", out, e))?;
+
+    for seed in seed..seed.saturating_add(count) {
+        let level = generate(seed, preset, &oas).map_err(|e| e.to_string())?;
+        let path = format!("{}/level_{}_{}.ron", out, profile, seed);
+
+        level.save(&path).map_err(|e| e.to_string())?;
+
+        println!(
+            "{} ({} moves)",
+            path,
+            solve(&level, &oas, preset.moves.1).unwrap_or_default()
+        );
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,206 @@
+use super::{GeneratorConf, RandomLevel};
+use crate::{
+    error::{self, GameResult},
+    game::rng::GameRng,
+    object::asset::ObjectAssetServer,
+    scene::level::LevelDesc,
+    state::AppState,
+    ui::game_ui::GameUINumberValue,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct DailyPlugin;
+
+impl Plugin for DailyPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DailyResults::load())
+            .add_systems(OnEnter(AppState::Title), remove_daily_challenge)
+            .add_systems(OnEnter(AppState::Game), reseed_daily_rng);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const DAILY_PROFILE: &str = "normal";
+
+const DAILY_DIRECTORY: &str = "alien-architect";
+
+const DAILY_RESULTS_FILE: &str = "daily.ron";
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyResult {
+    pub turns: usize,
+    pub score: usize,
+    pub code: String,
+}
+
+#[derive(Debug, Resource)]
+pub struct DailyChallenge {
+    pub date: String,
+    pub seed: u64,
+    pub desc: LevelDesc,
+    pub result: Option<DailyResult>,
+}
+
+impl DailyChallenge {
+    // Everyone playing on the same UTC date gets the same seed
+    pub fn today(conf: &GeneratorConf, oas: &ObjectAssetServer) -> GameResult<Self> {
+        let (year, month, day) = today();
+        let seed = (year as u64 * 10000) + (month as u64 * 100) + day as u64;
+
+        let random = RandomLevel::generate(seed, DAILY_PROFILE, conf, oas)?;
+
+        Ok(Self {
+            date: format!("{:04}-{:02}-{:02}", year, month, day),
+            seed,
+            desc: random.desc,
+            result: None,
+        })
+    }
+
+    // Seed followed by a short checksum of the level, so players can tell they played the same puzzle
+    pub fn code(&self) -> String {
+        return format!("{}-{:04X}", self.seed, self.desc.checksum() & 0xFFFF);
+    }
+
+    pub fn complete(&mut self, turns: usize, score: usize) -> DailyResult {
+        let result = DailyResult {
+            turns,
+            score,
+            code: self.code(),
+        };
+
+        self.result = Some(result.clone());
+
+        return result;
+    }
+}
+
+impl GameUINumberValue for DailyChallenge {
+    fn value(&self) -> usize {
+        return self.seed as usize;
+    }
+}
+
+// Best result for every date the daily challenge was completed on
+#[derive(Debug, Default, Resource, Serialize, Deserialize)]
+pub struct DailyResults {
+    results: BTreeMap<String, DailyResult>,
+}
+
+impl DailyResults {
+    fn load() -> Self {
+        let path = match results_path() {
+            Some(path) => path,
+            None => return Self::default(),
+        };
+
+        if !path.exists() {
+            return Self::default();
+        }
+
+        return match error::read_ron(&path.to_string_lossy()) {
+            Ok(results) => results,
+            Err(e) => {
+                error!("{}", e);
+                Self::default()
+            }
+        };
+    }
+
+    fn save(&self) {
+        let path = match results_path() {
+            Some(path) => path,
+            None => return,
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                error!("Failed to create `{}`: {}", parent.display(), e);
+                return;
+            }
+        }
+
+        if let Err(e) = error::write_ron(&path.to_string_lossy(), self) {
+            error!("{}", e);
+        }
+    }
+
+    pub fn get(&self, date: &str) -> Option<&DailyResult> {
+        return self.results.get(date);
+    }
+
+    // Keeps the higher score, or fewer turns for the same score
+    pub fn record(&mut self, date: &str, result: DailyResult) {
+        let better = match self.results.get(date) {
+            Some(best) => {
+                result.score > best.score
+                    || (result.score == best.score && result.turns < best.turns)
+            }
+            None => true,
+        };
+
+        if better {
+            self.results.insert(date.to_string(), result);
+            self.save();
+        }
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn remove_daily_challenge(mut commands: Commands) {
+    commands.remove_resource::<DailyChallenge>();
+}
+
+// Actors move the same way for everyone, restarting replays the same turns
+fn reseed_daily_rng(mut rng: ResMut<GameRng>, daily: Option<Res<DailyChallenge>>) {
+    if let Some(daily) = daily {
+        rng.reseed(daily.seed);
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+// Current UTC date as (year, month, day)
+pub fn today() -> (i64, u32, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86400);
+
+    return civil_from_days(days as i64);
+}
+
+fn results_path() -> Option<PathBuf> {
+    return dirs::data_dir().map(|dir| dir.join(DAILY_DIRECTORY).join(DAILY_RESULTS_FILE));
+}
+
+// Converts days since the Unix epoch to a (year, month, day) date
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return (year, month, day);
+}
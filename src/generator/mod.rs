@@ -0,0 +1,368 @@
+use crate::{
+    error::{self, GameError, GameResult},
+    object::{self, asset::ObjectAssetServer, turn, ObjectDesc, ObjectID},
+    scene::{error::GameErrorEvent, level::LevelDesc},
+    state::AppState,
+    ui::game_ui::GameUINumberValue,
+    world::{grid::Grid, World},
+};
+use bevy::{
+    prelude::*,
+    tasks::{AsyncComputeTaskPool, Task},
+};
+use daily::DailyChallenge;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Range;
+
+pub mod cli;
+pub mod daily;
+
+pub struct GeneratorPlugin;
+
+impl Plugin for GeneratorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(daily::DailyPlugin)
+            .add_systems(PreStartup, load_generator_conf)
+            .add_systems(OnEnter(AppState::Title), remove_random_level);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const GENERATOR_CONFIG_PATH: &str = "assets/generator-conf.ron";
+
+const GENERATOR_MAXIMUM_PLACEMENT_ITER: usize = 64;
+
+// Layouts that can't be solved within this many explored states are discarded
+const SOLVER_MAXIMUM_STATES: usize = 20_000;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratorProfile {
+    // Inclusive range of moves a generated level needs before the King reaches the Castle
+    pub moves: (usize, usize),
+    pub mountains: usize,
+    pub fields: usize,
+    pub buildings: Vec<ObjectID>,
+    pub actors: Vec<ObjectID>,
+    pub attempts: usize,
+}
+
+#[derive(Debug, Clone, Resource)]
+pub struct GeneratorConf {
+    profiles: HashMap<String, GeneratorProfile>,
+}
+
+impl GeneratorConf {
+    pub fn load() -> GameResult<Self> {
+        Ok(Self {
+            profiles: error::read_ron(GENERATOR_CONFIG_PATH)?,
+        })
+    }
+
+    pub fn profile(&self, name: &str) -> GameResult<&GeneratorProfile> {
+        return self
+            .profiles
+            .get(name)
+            .ok_or(GameError::MissingProfile(name.to_string()));
+    }
+}
+
+// Generated level played instead of the campaign, a new one is generated every time it's solved
+#[derive(Debug, Resource)]
+pub struct RandomLevel {
+    pub seed: u64,
+    pub profile: String,
+    pub desc: LevelDesc,
+}
+
+impl RandomLevel {
+    pub fn generate(
+        seed: u64,
+        profile: &str,
+        conf: &GeneratorConf,
+        oas: &ObjectAssetServer,
+    ) -> GameResult<Self> {
+        Ok(Self {
+            seed,
+            profile: profile.to_string(),
+            desc: generate(seed, conf.profile(profile)?, oas)?,
+        })
+    }
+}
+
+impl GameUINumberValue for RandomLevel {
+    fn value(&self) -> usize {
+        return self.seed as usize;
+    }
+}
+
+pub enum GeneratedLevel {
+    Random(RandomLevel),
+    Daily(DailyChallenge),
+}
+
+// Level being generated on another thread, solving the layouts can take a while.
+// `AppState::Generating` waits for it and inserts the result.
+#[derive(Resource)]
+pub struct PendingLevel(pub Task<GameResult<GeneratedLevel>>);
+
+impl PendingLevel {
+    pub fn random(seed: u64, profile: &str, conf: &GeneratorConf, oas: &ObjectAssetServer) -> Self {
+        let profile = profile.to_string();
+        let conf = conf.clone();
+        let oas = oas.without_images();
+
+        return Self(AsyncComputeTaskPool::get().spawn(async move {
+            RandomLevel::generate(seed, &profile, &conf, &oas).map(GeneratedLevel::Random)
+        }));
+    }
+
+    pub fn daily(conf: &GeneratorConf, oas: &ObjectAssetServer) -> Self {
+        let conf = conf.clone();
+        let oas = oas.without_images();
+
+        return Self(
+            AsyncComputeTaskPool::get().spawn(async move {
+                DailyChallenge::today(&conf, &oas).map(GeneratedLevel::Daily)
+            }),
+        );
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_generator_conf(mut commands: Commands, mut error_writer: EventWriter<GameErrorEvent>) {
+    let conf = match GeneratorConf::load() {
+        Ok(conf) => conf,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+
+            GeneratorConf {
+                profiles: HashMap::new(),
+            }
+        }
+    };
+
+    commands.insert_resource(conf);
+}
+
+fn remove_random_level(mut commands: Commands) {
+    commands.remove_resource::<RandomLevel>();
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+pub fn generate(
+    seed: u64,
+    profile: &GeneratorProfile,
+    oas: &ObjectAssetServer,
+) -> GameResult<LevelDesc> {
+    // Seeds are shared as codes, the sequence must not depend on the platform
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let grid = Grid::default();
+
+    let rows = grid.size.1;
+    let third = rows / 3;
+
+    for _ in 0..profile.attempts {
+        let mut desc = LevelDesc::new();
+
+        // King starts at the bottom of the board and the Castle at the top
+        if !place(&mut desc, ObjectID::King, 0..third, &mut rng, &grid, oas)
+            || !place(
+                &mut desc,
+                ObjectID::Castle,
+                (rows - third)..rows,
+                &mut rng,
+                &grid,
+                oas,
+            )
+        {
+            continue;
+        }
+
+        let terrain = std::iter::repeat_n(ObjectID::Mountain, profile.mountains)
+            .chain(std::iter::repeat_n(ObjectID::Field, profile.fields));
+
+        // Optional objects are skipped when there is no room left for them
+        for id in terrain
+            .chain(profile.buildings.iter().copied())
+            .chain(profile.actors.iter().copied())
+        {
+            place(&mut desc, id, 0..rows, &mut rng, &grid, oas);
+        }
+
+        match solve(&desc, oas, profile.moves.1) {
+            Some(moves) if moves >= profile.moves.0 => return Ok(desc),
+            _ => continue,
+        }
+    }
+
+    return Err(GameError::GenerationFailed(seed));
+}
+
+// Minimum number of drops needed before the King finds a path to the Castle.
+// Actors are treated as static, since their turns are random, and a drop may turn the Object.
+pub fn solve(desc: &LevelDesc, oas: &ObjectAssetServer, max_moves: usize) -> Option<usize> {
+    let mut grid = Grid::default();
+    grid.grid = desc.mask(&grid);
+
+    let index = |position: IVec2| ((position.y * grid.size.0 as i32) + position.x) as usize;
+
+    let mut world = World {
+        size: grid.size,
+        objects: vec![None; (grid.size.0 * grid.size.1) as usize],
+    };
+
+    let mut king = None;
+    let mut positions = vec![];
+
+    for (i, od) in desc.objects.iter().enumerate() {
+        let asset = oas.get(od.id).ok()?;
+        let cells = object::occupied_cells(od.position, asset.conf.occupy(od.orientation));
+
+        for cell in &cells {
+            world.objects[index(*cell)] = Some((Entity::from_raw(i as u32), od.id));
+        }
+
+        if od.id == ObjectID::King {
+            king = Some(cells[0]);
+        }
+
+        positions.push(cells);
+    }
+
+    let king = king?;
+
+    let mut visited = HashSet::new();
+    visited.insert(world.objects.clone());
+
+    let mut queue = VecDeque::new();
+    queue.push_back((world, positions, 0));
+
+    while let Some((world, positions, moves)) = queue.pop_front() {
+        if turn::find_path(king, &world, &grid).is_some() {
+            return Some(moves);
+        }
+
+        if moves >= max_moves {
+            continue;
+        }
+
+        for (i, od) in desc.objects.iter().enumerate() {
+            let asset = oas.get(od.id).ok()?;
+
+            if !asset.conf.selectable {
+                continue;
+            }
+
+            let entity = Entity::from_raw(i as u32);
+
+            // Objects can be turned while carried, so every orientation is a drop of its own
+            for orientation in 0..asset.conf.orientation_count() {
+                let valid = object::find_valid_cells(
+                    entity,
+                    od.id,
+                    positions[i][0],
+                    orientation,
+                    oas,
+                    &world,
+                    &grid,
+                );
+
+                for cell in 0..(grid.size.0 * grid.size.1) {
+                    let anchor = UVec2::new(cell % grid.size.0, cell / grid.size.0);
+                    let target = object::occupied_cells(anchor, asset.conf.occupy(orientation));
+
+                    if target == positions[i] {
+                        continue;
+                    }
+
+                    // Only drops onto free cells, actors merging into buildings are left out
+                    let free = target.iter().all(|cell| {
+                        grid.contains(*cell)
+                            && valid.contains(cell)
+                            && match world.objects[index(*cell)] {
+                                Some((target_entity, _)) => target_entity == entity,
+                                None => true,
+                            }
+                    });
+
+                    if !free {
+                        continue;
+                    }
+
+                    let mut objects = world.objects.clone();
+                    for cell in &positions[i] {
+                        objects[index(*cell)] = None;
+                    }
+                    for cell in &target {
+                        objects[index(*cell)] = Some((entity, od.id));
+                    }
+
+                    if !visited.insert(objects.clone()) {
+                        continue;
+                    }
+
+                    if visited.len() > SOLVER_MAXIMUM_STATES {
+                        return None;
+                    }
+
+                    let mut next = positions.clone();
+                    next[i] = target;
+
+                    queue.push_back((
+                        World {
+                            size: world.size,
+                            objects,
+                        },
+                        next,
+                        moves + 1,
+                    ));
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+fn place(
+    desc: &mut LevelDesc,
+    id: ObjectID,
+    rows: Range<u32>,
+    rng: &mut ChaCha8Rng,
+    grid: &Grid,
+    oas: &ObjectAssetServer,
+) -> bool {
+    for _ in 0..GENERATOR_MAXIMUM_PLACEMENT_ITER {
+        let position = UVec2::new(rng.gen_range(0..grid.size.0), rng.gen_range(rows.clone()));
+
+        desc.objects.push(ObjectDesc {
+            id,
+            position,
+            orientation: 0,
+        });
+
+        if desc.validate_placement(grid, oas).is_ok() {
+            return true;
+        }
+
+        desc.objects.pop();
+    }
+
+    return false;
+}
@@ -4,17 +4,14 @@ pub mod window {
     use bevy::window::{PresentMode, WindowMode};
 
     // Resolution => (160 x 144)
-    //     Scaled => (800 x 720)
+    //     Scaled => (800 x 720), viewport is rendered at the largest integer
+    //               scale that fits the window, and letterboxed.
 
     pub const VIEWPORT_RESOLUTION: (usize, usize) = (160, 144);
     pub const SCALE_FACTOR: usize = 5;
-    pub const RESOLUTION: (usize, usize) = (
-        VIEWPORT_RESOLUTION.0 * SCALE_FACTOR,
-        VIEWPORT_RESOLUTION.1 * SCALE_FACTOR,
-    );
     pub const TITLE: &str = "Alien Architect";
     pub const PRESENT_MODE: PresentMode = PresentMode::Fifo;
-    pub const RESIZABLE: bool = false;
+    pub const RESIZABLE: bool = true;
 
     pub const MODE: WindowMode = WindowMode::Windowed;
 }
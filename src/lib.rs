@@ -0,0 +1,42 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+
+pub mod animation;
+pub mod asset;
+pub mod audio;
+pub mod camera;
+pub mod error;
+pub mod game;
+pub mod generator;
+pub mod global;
+pub mod object;
+pub mod render;
+pub mod scene;
+pub mod settings;
+pub mod state;
+pub mod tween;
+pub mod ui;
+pub mod world;
+
+// Every plugin of the game, shared by the binary and the headless test harness
+pub struct GamePlugins;
+
+impl PluginGroup for GamePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        return PluginGroupBuilder::start::<Self>()
+            .add(settings::SettingsPlugin)
+            .add(camera::CameraPlugin)
+            .add(asset::AssetPlugin)
+            .add(state::StatePlugin)
+            .add(animation::AnimationPlugin)
+            .add(tween::TweenPlugin)
+            .add(render::RenderPlugin)
+            .add(audio::AudioPlugin)
+            .add(scene::ScenePlugin)
+            .add(ui::UIPlugin)
+            .add(world::WorldPlugin)
+            .add(game::GamePlugin)
+            .add(object::ObjectPlugin)
+            .add(generator::GeneratorPlugin);
+    }
+}
@@ -1,53 +1,32 @@
 use bevy::input::common_conditions::input_toggle_active;
 use bevy::prelude::*;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
-
-mod animation;
-mod asset;
-mod audio;
-mod camera;
-mod game;
-mod global;
-mod object;
-mod render;
-mod scene;
-mod state;
-mod ui;
-mod world;
+use gbjam::{generator, settings, GamePlugins};
 
 fn main() {
+    // Command line tools run instead of the game
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if generator::cli::run(&args) {
+        return;
+    }
+
+    let (settings, load_error) = settings::Settings::load();
+
     App::new()
         .add_plugins(
             DefaultPlugins
                 .set(ImagePlugin::default_nearest())
                 .set(WindowPlugin {
-                    primary_window: Some(Window {
-                        resolution: bevy::window::WindowResolution::new(
-                            global::window::RESOLUTION.0 as f32,
-                            global::window::RESOLUTION.1 as f32,
-                        ),
-                        title: format!("{} | {}", global::window::TITLE, global::app::PKG_VERSION),
-                        resizable: global::window::RESIZABLE,
-                        present_mode: global::window::PRESENT_MODE,
-                        mode: global::window::MODE,
-                        ..Default::default()
-                    }),
+                    primary_window: Some(settings.window()),
                     ..Default::default()
                 }),
         )
         .insert_resource(ClearColor(Color::BLACK))
+        .insert_resource(settings)
+        .insert_resource(load_error)
         .add_plugins(
             WorldInspectorPlugin::default().run_if(input_toggle_active(false, KeyCode::Grave)),
         )
-        .add_plugins(camera::CameraPlugin)
-        .add_plugins(asset::AssetPlugin)
-        .add_plugins(state::StatePlugin)
-        .add_plugins(animation::AnimationPlugin)
-        .add_plugins(audio::AudioPlugin)
-        .add_plugins(scene::ScenePlugin)
-        .add_plugins(ui::UIPlugin)
-        .add_plugins(world::WorldPlugin)
-        .add_plugins(game::GamePlugin)
-        .add_plugins(object::ObjectPlugin)
+        .add_plugins(GamePlugins)
         .run();
 }
@@ -1,4 +1,11 @@
-use crate::{animation::ObjectAnimationDesc, object::ObjectID};
+use crate::{
+    animation::ObjectAnimationDesc,
+    asset::watch::FileWatcher,
+    error::{self, GameError, GameResult},
+    object::ObjectID,
+    scene::{error::GameErrorEvent, level::ReloadLevelEvent},
+};
+use bevy::asset::HandleId;
 use bevy::prelude::*;
 use bevy::utils::HashMap;
 use serde::{Deserialize, Serialize};
@@ -7,7 +14,8 @@ pub struct AssetPlugin;
 
 impl Plugin for AssetPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, load_object_assets);
+        app.add_systems(PreStartup, load_object_assets)
+            .add_systems(Update, watch_object_conf);
     }
 }
 
@@ -21,7 +29,7 @@ const OBJECT_CONFIG_PATH: &str = "assets/object-conf.ron";
  * - Types
  */
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectConf {
     pub id: ObjectID,
     pub name: String,
@@ -29,9 +37,74 @@ pub struct ObjectConf {
     pub animated: Option<ObjectAnimationDesc>,
     pub occupy: Vec<IVec2>,
     pub offset: IVec2,
+    // Footprints rotating the Object cycles through after the one above
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orientations: Vec<ObjectOrientation>,
     assets: Vec<String>,
 }
 
+impl ObjectConf {
+    pub fn orientation_count(&self) -> usize {
+        return 1 + self.orientations.len();
+    }
+
+    // Orientation 0 and unknown ones use the base footprint
+    pub fn occupy(&self, orientation: usize) -> &Vec<IVec2> {
+        return match orientation
+            .checked_sub(1)
+            .and_then(|i| self.orientations.get(i))
+        {
+            Some(o) => &o.occupy,
+            None => &self.occupy,
+        };
+    }
+
+    pub fn offset(&self, orientation: usize) -> IVec2 {
+        return match orientation
+            .checked_sub(1)
+            .and_then(|i| self.orientations.get(i))
+        {
+            Some(o) => o.offset,
+            None => self.offset,
+        };
+    }
+
+    // Clips without frames have nothing to show, reject them before they're played
+    fn validate(&self) -> GameResult<()> {
+        if let Some(animated) = &self.animated {
+            for (name, clip) in &animated.clips {
+                if clip.frames.is_empty() {
+                    return Err(GameError::EmptyAnimationClip {
+                        id: self.id,
+                        clip: name.clone(),
+                    });
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    pub fn flip(&self, orientation: usize) -> bool {
+        return match orientation
+            .checked_sub(1)
+            .and_then(|i| self.orientations.get(i))
+        {
+            Some(o) => o.flip,
+            None => false,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectOrientation {
+    pub occupy: Vec<IVec2>,
+    pub offset: IVec2,
+    // Draws the sprite mirrored
+    #[serde(default)]
+    pub flip: bool,
+}
+
 #[derive(Debug)]
 pub struct ObjectAsset {
     pub conf: ObjectConf,
@@ -61,11 +134,43 @@ impl ObjectAssetServer {
         }
     }
 
-    pub fn get(&self, id: ObjectID) -> &ObjectAsset {
-        return match self.assets.get(&id) {
-            None => panic!("Failed to get object configuration for {}.", id.to_string()),
-            Some(asset) => asset,
-        };
+    // Configurations without any images, for tools that run without an AssetServer
+    pub fn from_confs(confs: Vec<ObjectConf>) -> Self {
+        let mut ocs = Self::new();
+
+        for c in confs {
+            ocs.assets.insert(
+                c.id,
+                ObjectAsset {
+                    conf: c,
+                    assets: vec![],
+                },
+            );
+        }
+
+        return ocs;
+    }
+
+    // Copy of the configurations, for generating levels off the main thread
+    pub fn without_images(&self) -> Self {
+        return Self::from_confs(self.assets.values().map(|a| a.conf.clone()).collect());
+    }
+
+    pub fn get(&self, id: ObjectID) -> GameResult<&ObjectAsset> {
+        return self.assets.get(&id).ok_or(GameError::MissingObjectConf(id));
+    }
+
+    // Every object image paired with the path it's loaded from
+    pub fn handles(&self) -> Vec<(&str, HandleId)> {
+        let mut handles = vec![];
+
+        for asset in self.assets.values() {
+            for (path, handle) in asset.conf.assets.iter().zip(asset.assets.iter()) {
+                handles.push((path.as_str(), handle.id()));
+            }
+        }
+
+        return handles;
     }
 }
 
@@ -73,21 +178,74 @@ impl ObjectAssetServer {
  * - System Functions
  */
 
-fn load_object_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn load_object_assets(
+    mut commands: Commands,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    asset_server: Res<AssetServer>,
+) {
     let mut ocs = ObjectAssetServer::new();
 
-    let confs: Vec<ObjectConf> = if let Ok(contents) = std::fs::read_to_string(OBJECT_CONFIG_PATH) {
-        ron::from_str(&contents).unwrap()
-    } else {
-        panic!(
-            "Failed to read file to load AssetConf, `{}`.",
-            OBJECT_CONFIG_PATH
-        );
+    // An empty ObjectAssetServer keeps the systems running until the error scene is shown
+    let confs = match load_object_confs() {
+        Ok(confs) => confs,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            vec![]
+        }
+    };
+
+    for c in confs {
+        ocs.assets.insert(c.id, ObjectAsset::new(c, &asset_server));
+    }
+
+    commands.insert_resource(ocs);
+}
+
+fn watch_object_conf(
+    mut commands: Commands,
+    mut watcher: Local<Option<FileWatcher>>,
+    mut event_writer: EventWriter<ReloadLevelEvent>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+) {
+    let watcher = watcher.get_or_insert_with(|| FileWatcher::new(OBJECT_CONFIG_PATH));
+
+    if !watcher.changed(time.delta()) {
+        return;
+    }
+
+    // Keep the current ObjectAssetServer if the new configuration is invalid
+    let confs = match load_object_confs() {
+        Ok(confs) => confs,
+        Err(e) => {
+            error!("{}", e);
+            return;
+        }
     };
 
+    let mut ocs = ObjectAssetServer::new();
+
     for c in confs {
         ocs.assets.insert(c.id, ObjectAsset::new(c, &asset_server));
     }
 
     commands.insert_resource(ocs);
+
+    event_writer.send(ReloadLevelEvent::new());
+
+    info!("Reloaded `{}`.", OBJECT_CONFIG_PATH);
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+pub fn load_object_confs() -> GameResult<Vec<ObjectConf>> {
+    let confs: Vec<ObjectConf> = error::read_ron(OBJECT_CONFIG_PATH)?;
+
+    for conf in &confs {
+        conf.validate()?;
+    }
+
+    return Ok(confs);
 }
@@ -1,8 +1,11 @@
 use crate::{
     animation::{Animate, AnimationMode},
+    error::{GameError, GameResult},
+    game::ufo::UFO,
     object::asset::ObjectAssetServer,
-    render::{RenderLayer, RENDER_LAYER},
+    render::{RenderLayer, RenderOrder},
     state::AppState,
+    tween::{Ease, Tween, TweenSystem, TWEEN_OBJECT_DURATION},
     world::{grid::Grid, World},
 };
 use bevy::{prelude::*, sprite::Anchor};
@@ -16,12 +19,20 @@ pub struct ObjectPlugin;
 
 impl Plugin for ObjectPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(asset::AssetPlugin)
+        app.register_type::<Object>()
+            .register_type::<Selectable>()
+            .add_plugins(asset::AssetPlugin)
             .add_plugins(turn::TurnPlugin)
             .add_event::<ObjectSelectEvent>()
             .add_systems(
                 PostUpdate,
-                (handle_select_object_event, update_object_image).run_if(in_state(AppState::Game)),
+                (
+                    handle_select_object_event,
+                    update_object_image,
+                    update_object_flip,
+                    sync_edited_objects.before(TweenSystem),
+                )
+                    .run_if(in_state(AppState::Game)),
             );
     }
 }
@@ -30,10 +41,13 @@ impl Plugin for ObjectPlugin {
  * - Types
  */
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ObjectDesc {
     pub id: ObjectID,
     pub position: UVec2,
+    // Index into the footprints of the Object's configuration, left out while it's the base one
+    #[serde(default, skip_serializing_if = "is_base_orientation")]
+    pub orientation: usize,
 }
 
 #[derive(Debug, Event)]
@@ -47,7 +61,8 @@ impl ObjectSelectEvent {
     }
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component)]
 pub struct Selectable {
     pub selected: bool,
 }
@@ -58,9 +73,18 @@ impl Selectable {
     }
 }
 
+impl Default for Selectable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Component, Serialize, Deserialize)]
+#[derive(
+    Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Component, Reflect, Serialize, Deserialize,
+)]
 pub enum ObjectID {
+    #[default]
     None,
     // Entity IDs
     King,
@@ -85,41 +109,102 @@ impl fmt::Display for ObjectID {
     }
 }
 
+// How `find_valid_cells` picks the cells a mobile Object can be dropped on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementRule {
+    // Any empty cell, or one holding one of the Objects
+    EmptyOr(&'static [ObjectID]),
+    // The cells around the Object's current position
+    Adjacent,
+    // Positions with at least the required number of the Object around the footprint
+    Neighbours(ObjectID, usize),
+    // The two lines crossing the Castle
+    CastleLines,
+    // Anywhere the whole footprint fits
+    Fits,
+}
+
+impl PlacementRule {
+    pub fn of(id: ObjectID) -> Option<Self> {
+        return match id {
+            ObjectID::Villager => Some(Self::EmptyOr(&[ObjectID::House, ObjectID::BigHouse])),
+            ObjectID::Cow => Some(Self::EmptyOr(&[ObjectID::Farm])),
+            ObjectID::House | ObjectID::BigHouse => Some(Self::Adjacent),
+            ObjectID::Farm => Some(Self::Neighbours(ObjectID::Field, 2)),
+            ObjectID::Tower => Some(Self::CastleLines),
+            ObjectID::Church => Some(Self::Fits),
+            ObjectID::Tavern => Some(Self::Neighbours(ObjectID::House, 4)),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for PlacementRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyOr(targets) => {
+                let targets: Vec<String> = targets.iter().map(|id| id.to_string()).collect();
+                write!(f, "Empty or {}", targets.join("/"))
+            }
+            Self::Adjacent => write!(f, "Adjacent"),
+            Self::Neighbours(id, required) => write!(f, "{}+ {}", required, id),
+            Self::CastleLines => write!(f, "Castle lines"),
+            Self::Fits => write!(f, "Fits"),
+        }
+    }
+}
+
+// Cells the World and the sprite were last placed for, tells edits from outside the game
+// apart from moves the game already applied
 #[derive(Debug, Component)]
+struct ObjectCells(Vec<IVec2>);
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
 pub struct Object {
     pub id: ObjectID,
     pub name: String,
     pub occupied: Vec<IVec2>,
     pub offset: IVec2,
+    pub orientation: usize,
 }
 
 impl Object {
     pub fn new(
         id: ObjectID,
         position: UVec2,
+        orientation: usize,
         texture_atlases: &mut Assets<TextureAtlas>,
         world: &mut World,
         commands: &mut Commands,
         grid: &Grid,
         oas: &ObjectAssetServer,
-    ) -> Entity {
-        let asset = oas.get(id);
+    ) -> GameResult<Entity> {
+        let asset = oas.get(id)?;
 
         // Calculate Object's occupied tiles
-        let y_mod = position.y % 2;
         let world_position = grid.cell_to_world(position);
+        let occupied = occupied_cells(position, asset.conf.occupy(orientation));
+        let offset = asset.conf.offset(orientation);
+        let flip = asset.conf.flip(orientation);
 
-        let mut occupied = vec![];
-        for (i, offset) in asset.conf.occupy.iter().enumerate() {
-            occupied.push(IVec2::new(
-                position.x as i32
-                    + (if i == 0 {
-                        offset.x
-                    } else {
-                        offset.x + y_mod as i32
-                    }),
-                position.y as i32 + offset.y,
-            ));
+        // Validate occupied tiles before spawning anything
+        for cell in &occupied {
+            let (valid, index) = validate_position(*cell, grid);
+
+            if !valid {
+                return Err(GameError::InvalidPosition {
+                    id,
+                    position: *cell,
+                });
+            }
+
+            if world.objects[index].is_some() {
+                return Err(GameError::OverlappingObjects {
+                    id,
+                    position: *cell,
+                });
+            }
         }
 
         // Create the Object
@@ -129,15 +214,21 @@ impl Object {
                     id,
                     occupied: occupied.clone(),
                     name: asset.conf.name.clone(),
-                    offset: asset.conf.offset,
+                    offset,
+                    orientation,
                 },
+                ObjectCells(occupied.clone()),
+                RenderOrder::from_cells(RenderLayer::Entity, &occupied),
                 Name::new(asset.conf.name.clone()),
             ))
             .id();
 
         // Give object Selectable property
         if asset.conf.selectable {
-            commands.entity(entity).insert(Selectable::new());
+            commands.entity(entity).insert((
+                Selectable::new(),
+                Tween::new(TWEEN_OBJECT_DURATION, Ease::CubicInOut),
+            ));
         }
 
         // Give object Animated property
@@ -155,14 +246,14 @@ impl Object {
                 commands.entity(entity).insert((
                     SpriteSheetBundle {
                         transform: Transform::from_xyz(
-                            world_position.x + asset.conf.offset.x as f32,
-                            world_position.y + asset.conf.offset.y as f32,
-                            (RENDER_LAYER[RenderLayer::Entity as usize] + grid.cell_order(position))
-                                as f32,
+                            world_position.x + offset.x as f32,
+                            world_position.y + offset.y as f32,
+                            0.0,
                         ),
                         texture_atlas: texture_atlases.add(texture_atlas),
                         sprite: TextureAtlasSprite {
                             index: 0,
+                            flip_x: flip,
                             anchor: Anchor::BottomLeft,
                             ..Default::default()
                         },
@@ -172,19 +263,20 @@ impl Object {
                         desc.atlas_size.0 * desc.atlas_size.1,
                         desc.interval,
                         AnimationMode::Loop,
-                    ),
+                    )
+                    .with_clips(desc.clips()),
                 ));
             }
             None => {
                 commands.entity(entity).insert(SpriteBundle {
                     transform: Transform::from_xyz(
-                        world_position.x + asset.conf.offset.x as f32,
-                        world_position.y + asset.conf.offset.y as f32,
-                        (RENDER_LAYER[RenderLayer::Entity as usize] + grid.cell_order(position))
-                            as f32,
+                        world_position.x + offset.x as f32,
+                        world_position.y + offset.y as f32,
+                        0.0,
                     ),
                     texture: asset.assets[0].clone(),
                     sprite: Sprite {
+                        flip_x: flip,
                         anchor: Anchor::BottomLeft,
                         ..Default::default()
                     },
@@ -200,7 +292,7 @@ impl Object {
             world.objects[index] = Some((entity, id));
         }
 
-        return entity;
+        return Ok(entity);
     }
 }
 
@@ -233,7 +325,13 @@ fn update_object_image(
     oas: Res<ObjectAssetServer>,
 ) {
     for (obj, selectable, mut handle) in &mut query {
-        let asset = oas.get(obj.id);
+        let asset = match oas.get(obj.id) {
+            Ok(asset) => asset,
+            Err(e) => {
+                error!("{}", e);
+                continue;
+            }
+        };
 
         if selectable.selected && asset.assets.len() > 1 {
             *handle = asset.assets[1].clone();
@@ -243,6 +341,124 @@ fn update_object_image(
     }
 }
 
+// Sprites are mirrored for orientations that ask for it, carried Objects show the UFO's orientation
+fn update_object_flip(
+    mut query: Query<(
+        Entity,
+        &Object,
+        Option<&mut Sprite>,
+        Option<&mut TextureAtlasSprite>,
+    )>,
+    ufo_query: Query<&UFO>,
+    oas: Res<ObjectAssetServer>,
+) {
+    let carried = ufo_query.get_single().ok().and_then(|ufo| ufo.carried());
+
+    for (entity, object, sprite, atlas_sprite) in &mut query {
+        let asset = match oas.get(object.id) {
+            Ok(asset) => asset,
+            Err(_) => continue,
+        };
+
+        let orientation = match carried {
+            Some((carried, orientation)) if carried == entity => orientation,
+            _ => object.orientation,
+        };
+        let flip = asset.conf.flip(orientation);
+
+        if let Some(mut sprite) = sprite {
+            if sprite.flip_x != flip {
+                sprite.flip_x = flip;
+            }
+        }
+        if let Some(mut sprite) = atlas_sprite {
+            if sprite.flip_x != flip {
+                sprite.flip_x = flip;
+            }
+        }
+    }
+}
+
+// Objects edited through reflection, e.g. in the inspector, are moved in the World and on screen
+fn sync_edited_objects(
+    mut query: Query<
+        (
+            Entity,
+            &mut Object,
+            &mut ObjectCells,
+            &mut Transform,
+            &mut RenderOrder,
+        ),
+        Changed<Object>,
+    >,
+    mut world: ResMut<World>,
+    grid: Res<Grid>,
+) {
+    for (entity, mut object, mut cells, mut transform, mut order) in &mut query {
+        if object.occupied == cells.0 {
+            continue;
+        }
+
+        // The game clears the previous cells itself when it moves, consumes or kills an Object
+        let held = cells.0.iter().any(|cell| {
+            let (valid, index) = validate_position(*cell, &grid);
+            return valid && matches!(world.objects[index], Some((target, _)) if target == entity);
+        });
+
+        if !held || object.occupied.is_empty() {
+            cells.0 = object.occupied.clone();
+            continue;
+        }
+
+        // Every new cell has to be on the board and free, or already held by the Object
+        let refusal = object.occupied.iter().find_map(|cell| {
+            let (valid, index) = validate_position(*cell, &grid);
+
+            if !valid {
+                return Some(format!("off the board to {}", cell));
+            }
+
+            return match world.objects[index] {
+                Some((target, id)) if target != entity => Some(format!("onto {} at {}", id, cell)),
+                _ => None,
+            };
+        });
+
+        if let Some(refusal) = refusal {
+            warn!(
+                "{} can't be moved {}, the edit is undone.",
+                object.name, refusal
+            );
+
+            object.occupied = cells.0.clone();
+            continue;
+        }
+
+        let previous = std::mem::replace(&mut cells.0, object.occupied.clone());
+
+        for cell in &previous {
+            let (valid, index) = validate_position(*cell, &grid);
+
+            if valid && matches!(world.objects[index], Some((target, _)) if target == entity) {
+                world.objects[index] = None;
+            }
+        }
+
+        for cell in &object.occupied {
+            let (_, index) = validate_position(*cell, &grid);
+            world.objects[index] = Some((entity, object.id));
+        }
+
+        let anchor = object.occupied[0];
+        let world_position = grid.cell_to_world(UVec2::new(anchor.x as u32, anchor.y as u32));
+
+        transform.translation.x = world_position.x + object.offset.x as f32;
+        transform.translation.y = world_position.y + object.offset.y as f32;
+
+        order.set_cells(&object.occupied);
+    }
+}
+
 /************************************************************
  * - Helper Functions
  */
@@ -251,37 +467,21 @@ pub fn find_valid_cells(
     entity: Entity,
     id: ObjectID,
     position: IVec2,
+    orientation: usize,
     oas: &ObjectAssetServer,
     world: &World,
     grid: &Grid,
 ) -> Vec<IVec2> {
-    return match id {
-        ObjectID::Villager => {
-            let mut valid = vec![];
-
-            for i in 0..(grid.size.0 * grid.size.1) {
-                let position = IVec2::new((i % grid.size.0) as i32, (i / grid.size.0) as i32);
-
-                // Validate current position
-                let (valid_position, index) = validate_position(position, grid);
-
-                if !valid_position {
-                    continue;
-                }
-
-                // If target position is empty or a farm push it as a valid position
-                match world.objects[index] {
-                    Some((_, target_id)) => match target_id {
-                        ObjectID::House | ObjectID::BigHouse => valid.push(position),
-                        _ => {}
-                    },
-                    None => valid.push(position),
-                }
-            }
-
-            valid
+    let rule = match PlacementRule::of(id) {
+        Some(rule) => rule,
+        None => {
+            error!("Can't find valid cells for immobile {}.", id.to_string());
+            return vec![];
         }
-        ObjectID::Cow => {
+    };
+
+    return match rule {
+        PlacementRule::EmptyOr(targets) => {
             let mut valid = vec![];
 
             for i in 0..(grid.size.0 * grid.size.1) {
@@ -294,10 +494,10 @@ pub fn find_valid_cells(
                     continue;
                 }
 
-                // If target position is empty or a farm push it as a valid position
+                // If target position is empty or one of the targets push it as a valid position
                 match world.objects[index] {
                     Some((_, target_id)) => {
-                        if let ObjectID::Farm = target_id {
+                        if targets.contains(&target_id) {
                             valid.push(position);
                         }
                     }
@@ -307,18 +507,20 @@ pub fn find_valid_cells(
 
             valid
         }
-        ObjectID::House => valid_tiles_for_adjacted_rule(position, world, grid),
-        ObjectID::BigHouse => valid_tiles_for_adjacted_rule(position, world, grid),
-        ObjectID::Farm => valid_tiles_for_n_number_of_neighbour_rule(
-            entity,
-            ObjectID::Farm,
-            ObjectID::Field,
-            2,
-            oas,
-            world,
-            grid,
-        ),
-        ObjectID::Tower => {
+        PlacementRule::Adjacent => valid_tiles_for_adjacted_rule(position, world, grid),
+        PlacementRule::Neighbours(target_id, required) => {
+            valid_tiles_for_n_number_of_neighbour_rule(
+                entity,
+                id,
+                target_id,
+                required,
+                orientation,
+                oas,
+                world,
+                grid,
+            )
+        }
+        PlacementRule::CastleLines => {
             let mut valid = vec![];
             let mut castle = IVec2::ZERO;
 
@@ -332,11 +534,9 @@ pub fn find_valid_cells(
                     continue;
                 }
 
-                if let Some((_, id)) = world.objects[index] {
-                    if let ObjectID::Castle = id {
-                        castle = position;
-                        break;
-                    }
+                if let Some((_, ObjectID::Castle)) = world.objects[index] {
+                    castle = position;
+                    break;
                 }
             }
 
@@ -351,13 +551,8 @@ pub fn find_valid_cells(
                     let (mut valid_position, index) = validate_position(position, grid);
 
                     if valid_position {
-                        match world.objects[index] {
-                            Some((target_entity, _)) => {
-                                if target_entity != entity {
-                                    valid_position = false;
-                                }
-                            }
-                            None => {}
+                        if let Some((target_entity, _)) = world.objects[index] {
+                            valid_position = target_entity == entity;
                         }
                     }
 
@@ -384,7 +579,7 @@ pub fn find_valid_cells(
                         y = grid.size.1 as i32 - 1;
                     }
 
-                    let position = IVec2::new(x + (i as i32 / 2), y - i as i32);
+                    let position = IVec2::new(x + (i / 2), y - i);
 
                     // Validate current position
                     let (valid_position, index) = validate_position(position, grid);
@@ -393,13 +588,9 @@ pub fn find_valid_cells(
                         continue;
                     }
 
-                    match world.objects[index] {
-                        Some((target_entity, _)) => {
-                            if target_entity != entity {
-                                continue;
-                            }
-                        }
-                        None => {}
+                    if matches!(world.objects[index], Some((target_entity, _)) if target_entity != entity)
+                    {
+                        continue;
                     }
 
                     valid.push(position);
@@ -408,8 +599,14 @@ pub fn find_valid_cells(
 
             valid
         }
-        ObjectID::Church => {
-            let asset = oas.get(ObjectID::Church);
+        PlacementRule::Fits => {
+            let asset = match oas.get(id) {
+                Ok(asset) => asset,
+                Err(e) => {
+                    error!("{}", e);
+                    return vec![];
+                }
+            };
 
             let mut valid = vec![];
 
@@ -419,7 +616,7 @@ pub fn find_valid_cells(
 
                 let mut found = true;
 
-                for (i, offset) in asset.conf.occupy.iter().enumerate() {
+                for (i, offset) in asset.conf.occupy(orientation).iter().enumerate() {
                     // Calculate position for every cell that Object will occupy
                     let current = IVec2::new(
                         position.x + (if i == 0 { offset.x } else { offset.x + y_mod }),
@@ -427,7 +624,7 @@ pub fn find_valid_cells(
                     );
 
                     // Validate current position
-                    let (valid, index) = validate_position(current, &grid);
+                    let (valid, index) = validate_position(current, grid);
 
                     if !valid {
                         found = false;
@@ -451,7 +648,7 @@ pub fn find_valid_cells(
 
                 // If a valid position is found push every tile that Object may occupy
                 if found {
-                    for (i, cell) in asset.conf.occupy.iter().enumerate() {
+                    for (i, cell) in asset.conf.occupy(orientation).iter().enumerate() {
                         let position = IVec2::new(
                             position.x + (if i == 0 { cell.x } else { cell.x + y_mod }),
                             position.y + cell.y,
@@ -466,19 +663,33 @@ pub fn find_valid_cells(
 
             valid
         }
-        ObjectID::Tavern => valid_tiles_for_n_number_of_neighbour_rule(
-            entity,
-            ObjectID::Tavern,
-            ObjectID::House,
-            4,
-            oas,
-            world,
-            grid,
-        ),
-        _ => panic!("Can't find valid cells for immobile {}.", id.to_string()),
     };
 }
 
+fn is_base_orientation(orientation: &usize) -> bool {
+    return *orientation == 0;
+}
+
+// Cells an Object placed at `position` covers, every offset after the first one is staggered
+pub fn occupied_cells(position: UVec2, occupy: &[IVec2]) -> Vec<IVec2> {
+    let y_mod = position.y % 2;
+
+    let mut occupied = vec![];
+    for (i, offset) in occupy.iter().enumerate() {
+        occupied.push(IVec2::new(
+            position.x as i32
+                + (if i == 0 {
+                    offset.x
+                } else {
+                    offset.x + y_mod as i32
+                }),
+            position.y as i32 + offset.y,
+        ));
+    }
+
+    return occupied;
+}
+
 fn get_adjected(position: IVec2) -> [(i32, i32); 9] {
     let y_mod = position.y % 2;
     return [
@@ -514,7 +725,7 @@ fn count_neighbour_id(
     entity: Entity,
     id: ObjectID,
     position: IVec2,
-    offsets: &Vec<IVec2>,
+    offsets: &[IVec2],
     world: &World,
     grid: &Grid,
 ) -> usize {
@@ -538,13 +749,8 @@ fn count_neighbour_id(
             return 0;
         }
 
-        match world.objects[index] {
-            Some((target_entity, _)) => {
-                if target_entity != entity {
-                    return 0;
-                }
-            }
-            None => {}
+        if matches!(world.objects[index], Some((target_entity, _)) if target_entity != entity) {
+            return 0;
         }
 
         // Check the adjacted cells for current cell
@@ -603,11 +809,18 @@ fn valid_tiles_for_n_number_of_neighbour_rule(
     self_id: ObjectID,
     target_id: ObjectID,
     required: usize,
+    orientation: usize,
     oas: &ObjectAssetServer,
     world: &World,
     grid: &Grid,
 ) -> Vec<IVec2> {
-    let asset = oas.get(self_id);
+    let asset = match oas.get(self_id) {
+        Ok(asset) => asset,
+        Err(e) => {
+            error!("{}", e);
+            return vec![];
+        }
+    };
 
     let mut valid = vec![];
 
@@ -615,13 +828,13 @@ fn valid_tiles_for_n_number_of_neighbour_rule(
         let position = IVec2::new((i % grid.size.0) as i32, (i / grid.size.0) as i32);
 
         // Count the neighbours for current position
-        let count =
-            count_neighbour_id(entity, target_id, position, &asset.conf.occupy, world, grid);
+        let occupy = asset.conf.occupy(orientation);
+        let count = count_neighbour_id(entity, target_id, position, occupy, world, grid);
 
         if count >= required {
             // Push every tile Objet can occupy as a valid one
             let y_mod = position.y % 2;
-            for (i, cell) in asset.conf.occupy.iter().enumerate() {
+            for (i, cell) in occupy.iter().enumerate() {
                 valid.push(IVec2::new(
                     position.x + (if i == 0 { cell.x } else { cell.x + y_mod }),
                     position.y + cell.y,
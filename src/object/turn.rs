@@ -1,10 +1,13 @@
 use super::{asset::ObjectAssetServer, validate_position, Object, ObjectID};
 use crate::{
-    game::{win::PlayerWinEvent, GameState},
+    animation::PlayAnimationEvent,
+    camera::CameraEffectEvent,
+    game::{rng::GameRng, win::PlayerWinEvent, GameState},
     object::get_adjected,
-    render::{RenderLayer, RENDER_LAYER},
-    scene::level::Score,
+    render::RenderOrder,
+    scene::level::{ScoreEvent, ScoreReason},
     state::AppState,
+    tween::TweenSystem,
     world::{grid::Grid, World},
 };
 use bevy::prelude::*;
@@ -17,6 +20,7 @@ impl Plugin for TurnPlugin {
         app.add_event::<ObjectsActTurnsEvent>().add_systems(
             PostUpdate,
             handle_objects_act_turns_event
+                .before(TweenSystem)
                 .run_if(in_state(AppState::Game).and_then(in_state(GameState::ObjectControlled))),
         );
     }
@@ -27,15 +31,28 @@ impl Plugin for TurnPlugin {
  */
 
 const OBJECT_RANDOM_MOVE_CHANCE: f32 = 0.6;
-const OBJECT_RANDOM_MOVE_MAXIMUM_ITER: usize = 250;
+
+// Strength and duration of the camera shake when the Assassin kills
+const OBJECT_KILL_SHAKE: (f32, f32) = (3.0, 0.4);
 
 /************************************************************
  * - Types
  */
 
-#[derive(Debug, Event)]
+#[derive(Debug, Default, Event)]
 pub struct ObjectsActTurnsEvent;
 
+// What the next turn is expected to do, see `predict_turn`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TurnPrediction {
+    // Villagers and Cows that get housed or farmed
+    pub consumed: Vec<IVec2>,
+    // Units standing in an Assassin's reach
+    pub killed: Vec<IVec2>,
+    // Cells units may wander onto and the chance that one of them does
+    pub wander: Vec<(IVec2, f32)>,
+}
+
 impl ObjectsActTurnsEvent {
     pub fn new() -> Self {
         Self
@@ -48,12 +65,15 @@ impl ObjectsActTurnsEvent {
 
 #[allow(unused_variables, unused_mut)]
 fn handle_objects_act_turns_event(
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut Object, &mut Transform)>,
+    mut query: Query<(Entity, &mut Object, &mut Transform, &mut RenderOrder)>,
     mut event_writer: EventWriter<PlayerWinEvent>,
     mut event_reader: EventReader<ObjectsActTurnsEvent>,
-    mut score: ResMut<Score>,
+    mut score_writer: EventWriter<ScoreEvent>,
+    mut anim_event_writer: EventWriter<PlayAnimationEvent>,
+    mut camera_event_writer: EventWriter<CameraEffectEvent>,
+    mut game_state: ResMut<NextState<GameState>>,
     mut world: ResMut<World>,
+    mut rng: ResMut<GameRng>,
     oas: Res<ObjectAssetServer>,
     grid: Res<Grid>,
 ) {
@@ -63,75 +83,58 @@ fn handle_objects_act_turns_event(
     event_reader.clear();
 
     let mut delete_queue = vec![];
+    let mut won = false;
 
     let mut sorted = vec![];
     for tuple in &mut query {
-        sorted.push(match tuple.1.id {
-            ObjectID::Villager => (0, tuple),
-            ObjectID::Cow => (1, tuple),
-            ObjectID::Assassin => (2, tuple),
-            ObjectID::King => (3, tuple),
-            _ => continue,
-        });
+        if let Some(order) = turn_order(tuple.1.id) {
+            sorted.push((order, tuple));
+        }
     }
-    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+    sorted.sort_by_key(|(order, _)| *order);
 
-    for (_, (entity, mut object, mut transform)) in sorted {
+    for (_, (entity, mut object, mut transform, mut order)) in sorted {
         match object.id {
             ObjectID::King => {
-                let path = find_path(&object, &world, &grid);
-                match path {
-                    Some((path, _)) => {
-                        event_writer.send(PlayerWinEvent::new(path));
-                    }
-                    None => {}
-                };
+                if let Some((path, _)) = find_path(object.occupied[0], &world, &grid) {
+                    event_writer.send(PlayerWinEvent::new(path));
+                    won = true;
+                }
             }
-            ObjectID::Villager => {
-                let desired = check_if_on_desired_tile(
-                    vec![ObjectID::House, ObjectID::BigHouse],
-                    &object,
-                    &world,
-                );
-
-                if desired {
-                    score.current += 1;
+            ObjectID::Villager | ObjectID::Cow => {
+                if let Some(reason) = consumed_by(object.id, object.occupied[0], &world) {
+                    score_writer.send(ScoreEvent::new(reason, 1));
 
                     delete_queue.push(entity);
 
                     continue;
                 }
 
-                move_to_random_adjected_tile(
+                let moved = move_to_random_adjected_tile(
                     entity,
                     &mut object,
                     &mut transform,
+                    &mut order,
                     &mut world,
+                    &mut rng,
                     &grid,
                 );
-            }
-            ObjectID::Cow => {
-                let desired = check_if_on_desired_tile(vec![ObjectID::Farm], &object, &world);
 
-                if desired {
-                    score.current += 1;
-
-                    delete_queue.push(entity);
-
-                    continue;
+                if moved {
+                    anim_event_writer.send(PlayAnimationEvent::new(entity, "walk"));
                 }
-
-                move_to_random_adjected_tile(
-                    entity,
-                    &mut object,
-                    &mut transform,
-                    &mut world,
-                    &grid,
-                );
             }
             ObjectID::Assassin => {
                 let mut targets = get_entities_to_kill(&mut world, &object, &grid);
 
+                if !targets.is_empty() {
+                    anim_event_writer.send(PlayAnimationEvent::new(entity, "stab"));
+                    camera_event_writer.send(CameraEffectEvent::shake(
+                        OBJECT_KILL_SHAKE.0,
+                        OBJECT_KILL_SHAKE.1,
+                    ));
+                }
+
                 for target in targets {
                     if delete_queue.contains(&target) {
                         continue;
@@ -145,7 +148,12 @@ fn handle_objects_act_turns_event(
     }
 
     for entity in delete_queue {
-        commands.entity(entity).despawn_recursive();
+        anim_event_writer.send(PlayAnimationEvent::despawn(entity, "death"));
+    }
+
+    // The UFO stays locked while the win animation plays
+    if !won {
+        game_state.set(GameState::PlayerControlled);
     }
 }
 
@@ -153,6 +161,97 @@ fn handle_objects_act_turns_event(
  * - Helper Functions
  */
 
+// Runs the rules of `handle_objects_act_turns_event` on a copy of the board without rolling the dice.
+// The kill markers are an approximation, units are checked where they stand now although they
+// wander before the Assassin strikes.
+pub fn predict_turn(units: &[(ObjectID, IVec2)], world: &World, grid: &Grid) -> TurnPrediction {
+    let mut prediction = TurnPrediction::default();
+
+    let mut sorted: Vec<(usize, ObjectID, IVec2)> = units
+        .iter()
+        .filter_map(|(id, position)| Some((turn_order(*id)?, *id, *position)))
+        .collect();
+    sorted.sort_by_key(|(order, _, _)| *order);
+
+    for (_, id, position) in sorted {
+        match id {
+            ObjectID::Villager | ObjectID::Cow => {
+                if consumed_by(id, position, world).is_some() {
+                    prediction.consumed.push(position);
+                    continue;
+                }
+
+                // A move picks one of the free diagonal cells at random
+                let free = wander_targets(position, world, grid);
+
+                for target in &free {
+                    let chance = OBJECT_RANDOM_MOVE_CHANCE / free.len() as f32;
+
+                    match prediction
+                        .wander
+                        .iter_mut()
+                        .find(|(cell, _)| cell == target)
+                    {
+                        Some((_, total)) => *total = 1.0 - (1.0 - *total) * (1.0 - chance),
+                        None => prediction.wander.push((*target, chance)),
+                    }
+                }
+            }
+            ObjectID::Assassin => {
+                for (cell, _) in kill_targets(position, world, grid) {
+                    if !prediction.consumed.contains(&cell) && !prediction.killed.contains(&cell) {
+                        prediction.killed.push(cell);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    return prediction;
+}
+
+// Objects act their turn in this order, None for Objects that don't act
+fn turn_order(id: ObjectID) -> Option<usize> {
+    return match id {
+        ObjectID::Villager => Some(0),
+        ObjectID::Cow => Some(1),
+        ObjectID::Assassin => Some(2),
+        ObjectID::King => Some(3),
+        _ => None,
+    };
+}
+
+// Villagers standing on a House and Cows standing on a Farm leave the board for points
+fn consumed_by(id: ObjectID, position: IVec2, world: &World) -> Option<ScoreReason> {
+    let index = ((position.y * world.size.0 as i32) + position.x) as usize;
+
+    return match (id, world.objects.get(index).copied().flatten()) {
+        (ObjectID::Villager, Some((_, ObjectID::House | ObjectID::BigHouse))) => {
+            Some(ScoreReason::Housed)
+        }
+        (ObjectID::Cow, Some((_, ObjectID::Farm))) => Some(ScoreReason::Farmed),
+        _ => None,
+    };
+}
+
+// Free diagonal cells a unit at `position` can wander onto
+fn wander_targets(position: IVec2, world: &World, grid: &Grid) -> Vec<IVec2> {
+    let mut free = vec![];
+
+    for offset in get_diagonal_adjected(position) {
+        let target = IVec2::new(position.x + offset.0, position.y + offset.1);
+
+        let (valid, index) = validate_position(target, grid);
+
+        if valid && world.objects[index].is_none() {
+            free.push(target);
+        }
+    }
+
+    return free;
+}
+
 fn get_diagonal_adjected(position: IVec2) -> [(i32, i32); 4] {
     let ymod = position.y % 2;
     return [
@@ -163,45 +262,17 @@ fn get_diagonal_adjected(position: IVec2) -> [(i32, i32); 4] {
     ];
 }
 
-fn check_if_on_desired_tile(desired: Vec<ObjectID>, object: &Object, world: &World) -> bool {
-    let position = object.occupied[0];
-    let index = ((position.y * world.size.0 as i32) + position.x) as usize;
-
-    match world.objects[index] {
-        Some((_, id)) => {
-            for target in desired {
-                if target != id {
-                    continue;
-                }
-
-                return true;
-            }
-
-            return false;
-        }
-        None => {
-            return false;
-        }
-    };
-}
-
-fn find_path(object: &Object, world: &World, grid: &Grid) -> Option<(Vec<IVec2>, i32)> {
+pub fn find_path(start: IVec2, world: &World, grid: &Grid) -> Option<(Vec<IVec2>, i32)> {
     let mut target = IVec2::ZERO;
     for i in 0..(grid.size.0 * grid.size.1) {
-        match world.objects[i as usize] {
-            Some((_, id)) => match id {
-                ObjectID::Castle => {
-                    target = IVec2::new((i % grid.size.0) as i32, (i / grid.size.0) as i32);
-                    break;
-                }
-                _ => continue,
-            },
-            None => continue,
+        if let Some((_, ObjectID::Castle)) = world.objects[i as usize] {
+            target = IVec2::new((i % grid.size.0) as i32, (i / grid.size.0) as i32);
+            break;
         }
     }
 
     return pathfinding::prelude::dijkstra(
-        &object.occupied[0],
+        &start,
         |&current| {
             let adjected = get_diagonal_adjected(current);
             let mut vec = vec![];
@@ -216,14 +287,10 @@ fn find_path(object: &Object, world: &World, grid: &Grid) -> Option<(Vec<IVec2>,
                     continue;
                 }
 
-                match world.objects[index] {
-                    Some((_, id)) => match id {
-                        ObjectID::Castle | ObjectID::King => {}
-                        _ => {
-                            continue;
-                        }
-                    },
-                    None => {}
+                if let Some((_, id)) = world.objects[index] {
+                    if !matches!(id, ObjectID::Castle | ObjectID::King) {
+                        continue;
+                    }
                 }
 
                 vec.push(pos);
@@ -235,99 +302,98 @@ fn find_path(object: &Object, world: &World, grid: &Grid) -> Option<(Vec<IVec2>,
     );
 }
 
-fn get_entities_to_kill(world: &mut World, object: &Object, grid: &Grid) -> Vec<Entity> {
-    let mut targets = vec![];
-    let adjected = get_adjected(object.occupied[0]);
+// Cells the Assassin standing at `position` stabs into
+pub fn kill_zone(position: IVec2, grid: &Grid) -> Vec<IVec2> {
+    let mut zone = vec![];
 
-    for offset in adjected {
-        let position = IVec2::new(
-            object.occupied[0].x + offset.0,
-            object.occupied[0].y + offset.1,
-        );
+    for offset in get_adjected(position) {
+        let target = IVec2::new(position.x + offset.0, position.y + offset.1);
 
         // Validate postion
-        let (valid, index) = validate_position(position, grid);
+        let (valid, _) = validate_position(target, grid);
 
         if !valid {
             continue;
         }
 
-        match world.objects[index] {
-            Some((entity, id)) => match id {
-                ObjectID::King | ObjectID::Villager | ObjectID::Cow => {
-                    // Push id to targets
-                    targets.push(entity);
+        zone.push(target);
+    }
 
-                    // Delete the Object from the World
-                    world.objects[index] = None;
-                }
-                _ => {}
-            },
-            None => continue,
+    return zone;
+}
+
+// Units in the reach of the Assassin standing at `position`
+fn kill_targets(position: IVec2, world: &World, grid: &Grid) -> Vec<(IVec2, Entity)> {
+    let mut targets = vec![];
+
+    for cell in kill_zone(position, grid) {
+        let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
+
+        if let Some((entity, ObjectID::King | ObjectID::Villager | ObjectID::Cow)) =
+            world.objects[index]
+        {
+            targets.push((cell, entity));
         }
     }
 
     return targets;
 }
 
+fn get_entities_to_kill(world: &mut World, object: &Object, grid: &Grid) -> Vec<Entity> {
+    let mut targets = vec![];
+
+    for (cell, entity) in kill_targets(object.occupied[0], world, grid) {
+        targets.push(entity);
+
+        // Delete the Object from the World
+        let index = ((cell.y * grid.size.0 as i32) + cell.x) as usize;
+        world.objects[index] = None;
+    }
+
+    return targets;
+}
+
 fn move_to_random_adjected_tile(
     entity: Entity,
     object: &mut Object,
     transform: &mut Transform,
+    order: &mut RenderOrder,
     world: &mut World,
+    rng: &mut GameRng,
     grid: &Grid,
-) {
+) -> bool {
     // Pick a random spot to move
-    let mut rng = rand::thread_rng();
-
-    let adjected = get_diagonal_adjected(object.occupied[0]);
+    let rng = rng.rng();
 
     let prob: f32 = rng.gen();
 
     if prob < (1.0 - OBJECT_RANDOM_MOVE_CHANCE) {
-        return;
+        return false;
     }
 
-    let mut iter = 0;
-    loop {
-        if iter >= OBJECT_RANDOM_MOVE_MAXIMUM_ITER {
-            break;
-        }
+    let free = wander_targets(object.occupied[0], world, grid);
 
-        let offset = adjected[rng.gen_range(0..adjected.len())];
-        let target = IVec2::new(
-            object.occupied[0].x + offset.0,
-            object.occupied[0].y + offset.1,
-        );
-
-        // Validate position
-        let (valid, index) = validate_position(target, grid);
+    if free.is_empty() {
+        return false;
+    }
 
-        if !valid {
-            iter += 1;
-            continue;
-        }
+    let target = free[rng.gen_range(0..free.len())];
+    let (_, index) = validate_position(target, grid);
 
-        if !world.objects[index].is_none() {
-            iter += 1;
-            continue;
-        }
+    // Move the object
+    world.objects[index] = Some((entity, object.id));
 
-        // Move the object
-        world.objects[index] = Some((entity, object.id));
+    let index = ((object.occupied[0].y * grid.size.0 as i32) + object.occupied[0].x) as usize;
+    world.objects[index] = None;
 
-        let index = ((object.occupied[0].y * grid.size.0 as i32) + object.occupied[0].x) as usize;
-        world.objects[index] = None;
+    object.occupied = vec![target];
 
-        object.occupied = vec![target];
+    let world_postition = grid.cell_to_world(UVec2::new(target.x as u32, target.y as u32));
 
-        let world_postition = grid.cell_to_world(UVec2::new(target.x as u32, target.y as u32));
-        let order = grid.cell_order(UVec2::new(target.x as u32, target.y as u32));
+    transform.translation.x = world_postition.x + object.offset.x as f32;
+    transform.translation.y = world_postition.y + object.offset.y as f32;
 
-        transform.translation.x = world_postition.x + object.offset.x as f32;
-        transform.translation.y = world_postition.y + object.offset.y as f32;
-        transform.translation.z = (RENDER_LAYER[RenderLayer::Entity as usize] + order) as f32;
+    order.set_cells(&object.occupied);
 
-        break;
-    }
+    return true;
 }
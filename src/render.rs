@@ -1,9 +1,28 @@
+use crate::{tween::TweenSystem, world::grid::Grid};
+use bevy::{prelude::*, transform::TransformSystem};
+
+pub struct RenderPlugin;
+
+impl Plugin for RenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            apply_render_order
+                .after(TweenSystem)
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
 /************************************************************
  * - Constants
  */
 
 pub const RENDER_LAYER: [u32; 5] = [0, 100, 200, 300, 400];
 
+// Sub-orders only separate sprites within a cell, so they never reach the next cell
+const RENDER_SUB_ORDER_STEP: f32 = 0.1;
+
 /************************************************************
  * - Types
  */
@@ -16,3 +35,68 @@ pub enum RenderLayer {
     UI,
     Overlay,
 }
+
+// Board sprites are drawn back to front by cell, `Transform.translation.z` is derived from it
+#[derive(Debug, Clone, Copy, Component)]
+pub struct RenderOrder {
+    pub layer: RenderLayer,
+    pub cell: IVec2,
+    pub sub: u32,
+}
+
+impl RenderOrder {
+    pub fn new(layer: RenderLayer, cell: IVec2) -> Self {
+        Self {
+            layer,
+            cell,
+            sub: 0,
+        }
+    }
+
+    // Multi-cell sprites sort by their front-most cell, so they cover everything behind them
+    pub fn from_cells(layer: RenderLayer, cells: &[IVec2]) -> Self {
+        return Self::new(layer, front_cell(cells));
+    }
+
+    pub fn with_sub(mut self, sub: u32) -> Self {
+        self.sub = sub;
+        return self;
+    }
+
+    pub fn set_cells(&mut self, cells: &[IVec2]) {
+        self.cell = front_cell(cells);
+    }
+
+    pub fn z(&self, grid: &Grid) -> f32 {
+        let cell = UVec2::new(self.cell.x.max(0) as u32, self.cell.y.max(0) as u32);
+
+        return (RENDER_LAYER[self.layer as usize] + grid.cell_order(cell)) as f32
+            + (self.sub as f32 * RENDER_SUB_ORDER_STEP);
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn apply_render_order(
+    mut query: Query<(&RenderOrder, &mut Transform), Changed<RenderOrder>>,
+    grid: Res<Grid>,
+) {
+    for (order, mut transform) in &mut query {
+        transform.translation.z = order.z(&grid);
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+// Lowest row is the closest to the camera, and the left-most cell of it
+fn front_cell(cells: &[IVec2]) -> IVec2 {
+    return cells
+        .iter()
+        .copied()
+        .min_by_key(|cell| (cell.y, cell.x))
+        .unwrap_or(IVec2::ZERO);
+}
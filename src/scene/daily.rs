@@ -0,0 +1,125 @@
+use crate::{
+    generator::daily::{DailyChallenge, DailyResults},
+    global::window,
+    render::{RenderLayer, RENDER_LAYER},
+    settings::Settings,
+    state::transition::{SceneTransitionEvent, TransitionEffect},
+    state::AppState,
+    ui::bitmap_text::BitmapText,
+};
+use bevy::{prelude::*, sprite::Anchor};
+
+pub struct DailyResultPlugin;
+
+impl Plugin for DailyResultPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::DailyResult), load_daily_result_scene)
+            .add_systems(OnExit(AppState::DailyResult), unload_daily_result_scene)
+            .add_systems(
+                Update,
+                control_daily_result_scene.run_if(in_state(AppState::DailyResult)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const DAILY_RESULT_MARGIN: f32 = 8.0;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Component)]
+struct DailyResultScene;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_daily_result_scene(
+    mut commands: Commands,
+    daily: Option<Res<DailyChallenge>>,
+    results: Res<DailyResults>,
+    settings: Res<Settings>,
+) {
+    let daily = match daily {
+        Some(daily) => daily,
+        None => {
+            error!("Entered DailyResult without a DailyChallenge.");
+            return;
+        }
+    };
+
+    let mut contents = format!("Daily Challenge {}\n\n", daily.date);
+
+    if let Some(result) = &daily.result {
+        contents += &format!("Turns: {}\nScore: {}\n\n", result.turns, result.score);
+    }
+
+    if let Some(best) = results.get(&daily.date) {
+        contents += &format!("Best: {} turns, {} points\n\n", best.turns, best.score);
+    }
+
+    contents += &format!(
+        "Code: {}\n\nPress {:?} to return to the title.",
+        daily.code(),
+        settings.input.lift
+    );
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            DailyResultScene,
+            Name::new("Daily Result Scene"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    DAILY_RESULT_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - DAILY_RESULT_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(&contents).with_width(
+                    window::VIEWPORT_RESOLUTION.0 as u32 - DAILY_RESULT_MARGIN as u32 * 2,
+                ),
+            ));
+        });
+}
+
+fn unload_daily_result_scene(mut commands: Commands, query: Query<Entity, With<DailyResultScene>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_daily_result_scene(
+    mut event_writer: EventWriter<SceneTransitionEvent>,
+    settings: Res<Settings>,
+    keys: Res<Input<KeyCode>>,
+) {
+    if keys.just_pressed(settings.input.lift) {
+        event_writer.send(SceneTransitionEvent::new(
+            TransitionEffect::Fade,
+            AppState::Title,
+        ));
+    }
+}
@@ -0,0 +1,498 @@
+use crate::{
+    asset::GameAssetServer,
+    game::ufo::{self, UFO},
+    global::window,
+    object::{self, asset::ObjectAssetServer, Object, ObjectDesc, ObjectID},
+    render::{RenderLayer, RENDER_LAYER},
+    scene::level::{self, Level, LevelDesc},
+    settings::Settings,
+    state::{
+        transition::{SceneTransitionEvent, TransitionEffect},
+        AppState,
+    },
+    ui::bitmap_text::BitmapText,
+    world::{
+        self,
+        grid::Grid,
+        tile::{TileState, TileStateChangeEvent},
+    },
+};
+use bevy::prelude::*;
+
+pub struct EditorPlugin;
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EditorRebuildEvent>()
+            .add_systems(Update, enter_editor.run_if(in_state(AppState::Title)))
+            .add_systems(Update, leave_test_play.run_if(in_state(AppState::Game)))
+            .add_systems(OnEnter(AppState::Title), remove_editor_level)
+            .add_systems(OnEnter(AppState::Editor), load_editor)
+            .add_systems(
+                OnExit(AppState::Editor),
+                (level::unload_level, unload_editor_ui),
+            )
+            .add_systems(
+                Update,
+                (
+                    control_editor,
+                    (level::unload_level, apply_deferred, rebuild_editor_level)
+                        .chain()
+                        .run_if(on_event::<EditorRebuildEvent>()),
+                    update_editor_ui,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Editor)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const EDITOR_OBJECTS: [ObjectID; 13] = [
+    ObjectID::King,
+    ObjectID::Castle,
+    ObjectID::Villager,
+    ObjectID::Cow,
+    ObjectID::Assassin,
+    ObjectID::Mountain,
+    ObjectID::Field,
+    ObjectID::House,
+    ObjectID::BigHouse,
+    ObjectID::Farm,
+    ObjectID::Tower,
+    ObjectID::Church,
+    ObjectID::Tavern,
+];
+
+// Editor keys are fixed, the configurable bindings are reused for the cursor
+const EDITOR_KEY: KeyCode = KeyCode::F2;
+
+const EDITOR_PREVIOUS_OBJECT_KEY: KeyCode = KeyCode::Q;
+
+const EDITOR_NEXT_OBJECT_KEY: KeyCode = KeyCode::E;
+
+const EDITOR_TOGGLE_CELL_KEY: KeyCode = KeyCode::T;
+
+const EDITOR_UFO_START_KEY: KeyCode = KeyCode::U;
+
+const EDITOR_TEST_PLAY_KEY: KeyCode = KeyCode::Tab;
+
+const EDITOR_SAVE_KEY: KeyCode = KeyCode::F5;
+
+const EDITOR_UI_MARGIN: (f32, f32) = (4.0, 4.0);
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Event)]
+struct EditorRebuildEvent;
+
+#[derive(Debug, Resource)]
+pub struct EditorLevel {
+    pub desc: LevelDesc,
+    pub playing: bool,
+    path: &'static str,
+    cursor: IVec2,
+    selected: usize,
+    holding: Option<usize>,
+    status: String,
+}
+
+impl EditorLevel {
+    fn new(desc: LevelDesc, path: &'static str) -> Self {
+        Self {
+            cursor: desc.ufo_start(),
+            desc,
+            playing: false,
+            path,
+            selected: 0,
+            holding: None,
+            status: String::new(),
+        }
+    }
+
+    // Plays a level straight away, like test-play started from the editor
+    pub fn test_play(desc: LevelDesc, path: &'static str) -> Self {
+        let mut editor = Self::new(desc, path);
+        editor.playing = true;
+
+        return editor;
+    }
+
+    // Index of the ObjectDesc covering `position`
+    fn object_at(&self, position: IVec2, oas: &ObjectAssetServer) -> Option<usize> {
+        return self
+            .desc
+            .objects
+            .iter()
+            .position(|od| match oas.get(od.id) {
+                Ok(asset) => object::occupied_cells(od.position, asset.conf.occupy(od.orientation))
+                    .contains(&position),
+                Err(_) => false,
+            });
+    }
+
+    // Applies the edit only if the level is still placeable afterwards
+    fn edit(
+        &mut self,
+        grid: &Grid,
+        oas: &ObjectAssetServer,
+        event_writer: &mut EventWriter<EditorRebuildEvent>,
+        f: impl FnOnce(&mut LevelDesc),
+    ) -> bool {
+        let mut desc = self.desc.clone();
+        f(&mut desc);
+
+        if let Err(e) = desc.validate_placement(grid, oas) {
+            self.status = e.to_string();
+            return false;
+        }
+
+        self.desc = desc;
+        self.status.clear();
+
+        event_writer.send(EditorRebuildEvent);
+
+        return true;
+    }
+}
+
+#[derive(Debug, Component)]
+struct EditorUI;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn enter_editor(
+    mut commands: Commands,
+    mut event_writer: EventWriter<SceneTransitionEvent>,
+    level: Res<Level>,
+    keys: Res<Input<KeyCode>>,
+) {
+    if !keys.just_pressed(EDITOR_KEY) {
+        return;
+    }
+
+    // Start from an empty level if the file is missing or broken
+    let editor = match LevelDesc::load(level.path()) {
+        Ok(desc) => EditorLevel::new(desc, level.path()),
+        Err(e) => {
+            let mut editor = EditorLevel::new(LevelDesc::new(), level.path());
+            editor.status = e.to_string();
+            editor
+        }
+    };
+
+    commands.insert_resource(editor);
+
+    event_writer.send(SceneTransitionEvent::new(
+        TransitionEffect::Fade,
+        AppState::Editor,
+    ));
+}
+
+fn leave_test_play(
+    mut event_writer: EventWriter<SceneTransitionEvent>,
+    editor: Option<Res<EditorLevel>>,
+    keys: Res<Input<KeyCode>>,
+) {
+    let playing = editor.is_some_and(|editor| editor.playing);
+
+    if playing && keys.just_pressed(EDITOR_TEST_PLAY_KEY) {
+        event_writer.send(SceneTransitionEvent::new(
+            TransitionEffect::Fade,
+            AppState::Editor,
+        ));
+    }
+}
+
+fn remove_editor_level(mut commands: Commands) {
+    commands.remove_resource::<EditorLevel>();
+}
+
+fn load_editor(
+    mut commands: Commands,
+    mut event_writer: EventWriter<EditorRebuildEvent>,
+    mut editor: ResMut<EditorLevel>,
+) {
+    editor.playing = false;
+
+    commands.spawn((
+        SpatialBundle::from_transform(Transform::from_xyz(
+            EDITOR_UI_MARGIN.0,
+            window::VIEWPORT_RESOLUTION.1 as f32 - EDITOR_UI_MARGIN.1,
+            RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+        )),
+        BitmapText::new("")
+            .with_width(window::VIEWPORT_RESOLUTION.0 as u32 - EDITOR_UI_MARGIN.0 as u32 * 2),
+        EditorUI,
+        Name::new("Editor UI"),
+    ));
+
+    event_writer.send(EditorRebuildEvent);
+}
+
+fn unload_editor_ui(mut commands: Commands, query: Query<Entity, With<EditorUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_editor(
+    mut ufo_query: Query<(&mut UFO, &mut Transform)>,
+    mut tile_event_writer: EventWriter<TileStateChangeEvent>,
+    mut rebuild_event_writer: EventWriter<EditorRebuildEvent>,
+    mut trns_event_writer: EventWriter<SceneTransitionEvent>,
+    mut editor: ResMut<EditorLevel>,
+    settings: Res<Settings>,
+    oas: Res<ObjectAssetServer>,
+    grid: Res<Grid>,
+    keys: Res<Input<KeyCode>>,
+) {
+    let input = &settings.input;
+
+    // Leave the editor, unsaved changes are discarded
+    if keys.just_pressed(input.settings) {
+        trns_event_writer.send(SceneTransitionEvent::new(
+            TransitionEffect::Fade,
+            AppState::Title,
+        ));
+        return;
+    }
+
+    // Cursor
+    let y_mod = editor.cursor.y % 2;
+    let mut target = editor.cursor;
+
+    if keys.just_pressed(input.right) {
+        target += IVec2::new(0 + y_mod, -1);
+    }
+    if keys.just_pressed(input.left) {
+        target += IVec2::new(-1 + y_mod, 1);
+    }
+    if keys.just_pressed(input.up) {
+        target += IVec2::new(0 + y_mod, 1);
+    }
+    if keys.just_pressed(input.down) {
+        target += IVec2::new(-1 + y_mod, -1);
+    }
+
+    // Disabled cells are reachable as well, so they can be toggled back on
+    if target != editor.cursor && grid.contains(target) {
+        let previous = editor.cursor;
+        editor.cursor = target;
+
+        tile_event_writer.send(TileStateChangeEvent::new(
+            previous,
+            editor_tile_state(previous, &editor.desc),
+        ));
+        tile_event_writer.send(TileStateChangeEvent::new(target, TileState::Selected));
+
+        for (mut ufo, mut transform) in &mut ufo_query {
+            ufo::ufo_place(target, &mut ufo, &mut transform, &grid);
+        }
+    }
+
+    // Object selection
+    if keys.just_pressed(EDITOR_PREVIOUS_OBJECT_KEY) {
+        editor.selected = (editor.selected + EDITOR_OBJECTS.len() - 1) % EDITOR_OBJECTS.len();
+    }
+    if keys.just_pressed(EDITOR_NEXT_OBJECT_KEY) {
+        editor.selected = (editor.selected + 1) % EDITOR_OBJECTS.len();
+    }
+
+    let cursor = editor.cursor;
+
+    // Place, pick up & drop
+    if keys.just_pressed(input.lift) {
+        match editor.holding {
+            Some(index) => {
+                let moved = editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+                    desc.objects[index].position = UVec2::new(cursor.x as u32, cursor.y as u32);
+                });
+
+                if moved {
+                    editor.holding = None;
+                }
+            }
+            None => match editor.object_at(cursor, &oas) {
+                Some(index) => {
+                    editor.holding = Some(index);
+                    editor.status = format!("Moving {}.", editor.desc.objects[index].id);
+                }
+                None => {
+                    let id = EDITOR_OBJECTS[editor.selected];
+
+                    editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+                        desc.objects.push(ObjectDesc {
+                            id,
+                            position: UVec2::new(cursor.x as u32, cursor.y as u32),
+                            orientation: 0,
+                        });
+                    });
+                }
+            },
+        }
+    }
+
+    // Delete
+    if keys.just_pressed(input.cancel) {
+        if editor.holding.is_some() {
+            editor.holding = None;
+            editor.status.clear();
+        } else if let Some(index) = editor.object_at(cursor, &oas) {
+            editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+                desc.objects.remove(index);
+            });
+        }
+    }
+
+    // Rotate the held Object, or the one under the cursor
+    if keys.just_pressed(input.rotate) {
+        if let Some(index) = editor.holding.or_else(|| editor.object_at(cursor, &oas)) {
+            let count = oas
+                .get(editor.desc.objects[index].id)
+                .map_or(1, |asset| asset.conf.orientation_count());
+
+            editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+                desc.objects[index].orientation = (desc.objects[index].orientation + 1) % count;
+            });
+        }
+    }
+
+    // Grid mask
+    if keys.just_pressed(EDITOR_TOGGLE_CELL_KEY) {
+        editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+            let mut mask = desc.mask(&grid);
+            let index = ((cursor.y * grid.size.0 as i32) + cursor.x) as usize;
+
+            mask[index] = if mask[index] == 0 { 1 } else { 0 };
+            desc.grid = Some(mask);
+        });
+    }
+
+    // UFO start
+    if keys.just_pressed(EDITOR_UFO_START_KEY) {
+        editor.edit(&grid, &oas, &mut rebuild_event_writer, |desc| {
+            desc.ufo = Some(UVec2::new(cursor.x as u32, cursor.y as u32));
+        });
+    }
+
+    // Test-play
+    if keys.just_pressed(EDITOR_TEST_PLAY_KEY) {
+        match editor.desc.validate(&grid, &oas) {
+            Ok(_) => {
+                editor.playing = true;
+
+                trns_event_writer.send(SceneTransitionEvent::new(
+                    TransitionEffect::Fade,
+                    AppState::Game,
+                ));
+            }
+            Err(e) => editor.status = e.to_string(),
+        }
+    }
+
+    // Save
+    if keys.just_pressed(EDITOR_SAVE_KEY) {
+        let result = editor
+            .desc
+            .validate(&grid, &oas)
+            .and_then(|_| editor.desc.save(editor.path));
+
+        editor.status = match result {
+            Ok(_) => format!("Saved `{}`.", editor.path),
+            Err(e) => e.to_string(),
+        };
+    }
+}
+
+fn rebuild_editor_level(
+    mut commands: Commands,
+    mut event_writer: EventWriter<TileStateChangeEvent>,
+    mut world: ResMut<world::World>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut grid: ResMut<Grid>,
+    mut editor: ResMut<EditorLevel>,
+    oas: Res<ObjectAssetServer>,
+    gas: Res<GameAssetServer>,
+) {
+    grid.grid = editor.desc.mask(&grid);
+
+    world::generate_tiles(&grid, &mut commands);
+
+    // Unlike `load_level` a level without a King or a Castle is still shown
+    world.objects.fill(None);
+    let mut status = None;
+    for od in &editor.desc.objects {
+        if let Err(e) = Object::new(
+            od.id,
+            od.position,
+            od.orientation,
+            &mut texture_atlases,
+            &mut world,
+            &mut commands,
+            &grid,
+            &oas,
+        ) {
+            status = Some(e.to_string());
+        }
+    }
+
+    if let Some(status) = status {
+        editor.status = status;
+    }
+
+    event_writer.send(TileStateChangeEvent::new(
+        editor.desc.ufo_start(),
+        TileState::Path,
+    ));
+
+    if let Err(e) = UFO::new(editor.cursor, &grid, &gas, &mut commands, &mut event_writer) {
+        editor.status = e.to_string();
+    }
+}
+
+fn update_editor_ui(
+    mut query: Query<&mut BitmapText, With<EditorUI>>,
+    editor: Res<EditorLevel>,
+    oas: Res<ObjectAssetServer>,
+) {
+    if !editor.is_changed() {
+        return;
+    }
+
+    let hovered = match editor.object_at(editor.cursor, &oas) {
+        Some(index) => editor.desc.objects[index].id.to_string(),
+        None => String::from("-"),
+    };
+
+    for mut text in &mut query {
+        text.text = format!(
+            "{}\n({}, {}) {} | Place: {}\n{}",
+            editor.path,
+            editor.cursor.x,
+            editor.cursor.y,
+            hovered,
+            EDITOR_OBJECTS[editor.selected],
+            editor.status
+        );
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn editor_tile_state(position: IVec2, desc: &LevelDesc) -> TileState {
+    return if position == desc.ufo_start() {
+        TileState::Path
+    } else {
+        TileState::Default
+    };
+}
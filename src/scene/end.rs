@@ -1,6 +1,11 @@
 use crate::{
+    asset::GameAssetServer,
+    game::record::LevelRecords,
+    global::window,
     render::{RenderLayer, RENDER_LAYER},
+    scene::{error::GameErrorEvent, level::Score},
     state::AppState,
+    ui::bitmap_text::{BitmapText, TextAlign, BITMAP_TEXT_DEFAULT_FONT},
 };
 use bevy::{prelude::*, sprite::Anchor};
 
@@ -17,7 +22,10 @@ impl Plugin for EndPlugin {
  * - Constants
  */
 
-const END_IMAGE_PATH: &str = "ui/end_screen.png";
+// Bottom left of the score totals
+const END_TOTALS_POSITION: (f32, f32) = (8.0, 8.0);
+
+const END_RECORDS_MARGIN: f32 = 8.0;
 
 /************************************************************
  * - Types
@@ -33,23 +41,88 @@ impl EndScreen {
 }
 
 /************************************************************
- * - Constantss
+ * - System Functions
  */
 
-fn load_end(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.spawn((
-        SpriteBundle {
-            transform: Transform::from_xyz(0.0, 0.0, RENDER_LAYER[RenderLayer::UI as usize] as f32),
-            texture: asset_server.load(END_IMAGE_PATH),
-            sprite: Sprite {
-                anchor: Anchor::BottomLeft,
+fn load_end(
+    mut commands: Commands,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
+    score: Res<Score>,
+    records: Res<LevelRecords>,
+) {
+    let (asset, font) = match (
+        gas.texture("end_screen"),
+        gas.font(BITMAP_TEXT_DEFAULT_FONT),
+    ) {
+        (Ok(asset), Ok(font)) => (asset, font),
+        (Err(e), _) | (_, Err(e)) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    };
+
+    let mut contents = String::new();
+
+    for (reason, amount) in score.totals() {
+        contents += &format!("{}: {}\n", reason.label(), amount);
+    }
+
+    contents += &format!("Score: {}", score.current);
+
+    // Bitmap text hangs below its position, so the totals are raised by their height
+    let totals_top =
+        END_TOTALS_POSITION.1 + (contents.lines().count() as u32 * font.desc.line_height) as f32;
+
+    // Best score and turns of every campaign level
+    let mut bests = String::from("Best\n");
+
+    for (i, checksum) in records.checksums().iter().enumerate() {
+        bests += &match checksum.and_then(|checksum| records.get(checksum)) {
+            Some(record) => format!("{:>2} {:>3} {:>2}t\n", i + 1, record.score, record.turns),
+            None => format!("{:>2}   -  -\n", i + 1),
+        };
+    }
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                ),
+                texture: asset.handle.clone(),
+                sprite: Sprite {
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
                 ..Default::default()
             },
-            ..Default::default()
-        },
-        EndScreen::new(),
-        Name::new("End Screen"),
-    ));
+            EndScreen::new(),
+            Name::new("End Screen"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    END_TOTALS_POSITION.0,
+                    totals_top,
+                    1.0,
+                )),
+                BitmapText::new(&contents),
+                Name::new("End Score Totals"),
+            ));
+
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    window::VIEWPORT_RESOLUTION.0 as f32 - END_RECORDS_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - END_RECORDS_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(&bests).with_align(TextAlign::Right),
+                Name::new("End Level Records"),
+            ));
+        });
 }
 
 fn unload_end(mut commands: Commands, query: Query<Entity, With<EndScreen>>) {
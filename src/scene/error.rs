@@ -0,0 +1,140 @@
+use crate::{
+    error::GameError,
+    global::window,
+    render::{RenderLayer, RENDER_LAYER},
+    settings::Settings,
+    state::transition::{SceneTransitionEvent, TransitionEffect},
+    state::AppState,
+    ui::bitmap_text::BitmapText,
+};
+use bevy::{prelude::*, sprite::Anchor};
+
+pub struct ErrorPlugin;
+
+impl Plugin for ErrorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<GameErrorEvent>()
+            .init_resource::<ErrorMessage>()
+            .add_systems(OnEnter(AppState::Error), load_error_scene)
+            .add_systems(OnExit(AppState::Error), unload_error_scene)
+            .add_systems(
+                Update,
+                control_error_scene.run_if(in_state(AppState::Error)),
+            )
+            .add_systems(PostUpdate, handle_game_error_event);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const ERROR_SCENE_MARGIN: f32 = 8.0;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Event)]
+pub struct GameErrorEvent {
+    error: GameError,
+}
+
+impl GameErrorEvent {
+    pub fn new(error: GameError) -> Self {
+        Self { error }
+    }
+}
+
+#[derive(Debug, Resource, Default)]
+struct ErrorMessage(String);
+
+#[derive(Debug, Component)]
+struct ErrorScene;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn handle_game_error_event(
+    mut event_reader: EventReader<GameErrorEvent>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut message: ResMut<ErrorMessage>,
+) {
+    // Only the first error is shown, the rest are usually caused by it
+    let mut first = None;
+    for e in event_reader.iter() {
+        error!("{}", e.error);
+
+        if first.is_none() {
+            first = Some(e.error.to_string());
+        }
+    }
+
+    if let Some(first) = first {
+        message.0 = first;
+
+        app_state.set(AppState::Error);
+    }
+}
+
+fn load_error_scene(mut commands: Commands, settings: Res<Settings>, message: Res<ErrorMessage>) {
+    let contents = format!(
+        "Something went wrong:\n\n{}\n\nPress {:?} to return to the title.",
+        message.0, settings.input.lift
+    );
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ErrorScene,
+            Name::new("Error Scene"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    ERROR_SCENE_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - ERROR_SCENE_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(&contents).with_width(
+                    window::VIEWPORT_RESOLUTION.0 as u32 - ERROR_SCENE_MARGIN as u32 * 2,
+                ),
+            ));
+        });
+}
+
+fn unload_error_scene(mut commands: Commands, query: Query<Entity, With<ErrorScene>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_error_scene(
+    mut event_writer: EventWriter<SceneTransitionEvent>,
+    settings: Res<Settings>,
+    keys: Res<Input<KeyCode>>,
+) {
+    if keys.just_pressed(settings.input.lift) {
+        event_writer.send(SceneTransitionEvent::new(
+            TransitionEffect::Fade,
+            AppState::Title,
+        ));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    generator::{GeneratedLevel, PendingLevel},
+    global::window,
+    render::{RenderLayer, RENDER_LAYER},
+    scene::error::GameErrorEvent,
+    state::transition::{SceneTransitionEvent, TransitionEffect},
+    state::AppState,
+    ui::bitmap_text::{BitmapText, TextAlign},
+};
+use bevy::{prelude::*, sprite::Anchor};
+use futures_lite::future;
+
+pub struct GeneratingPlugin;
+
+impl Plugin for GeneratingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Generating), load_generating_scene)
+            .add_systems(OnExit(AppState::Generating), unload_generating_scene)
+            .add_systems(
+                Update,
+                poll_pending_level.run_if(in_state(AppState::Generating)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const GENERATING_LABEL: &str = "GENERATING";
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Component)]
+struct GeneratingScene;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_generating_scene(mut commands: Commands) {
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            GeneratingScene,
+            Name::new("Generating Scene"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    window::VIEWPORT_RESOLUTION.0 as f32 / 2.0,
+                    window::VIEWPORT_RESOLUTION.1 as f32 / 2.0,
+                    1.0,
+                )),
+                BitmapText::new(GENERATING_LABEL).with_align(TextAlign::Center),
+                Name::new("Generating Label"),
+            ));
+        });
+}
+
+fn unload_generating_scene(mut commands: Commands, query: Query<Entity, With<GeneratingScene>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn poll_pending_level(
+    mut commands: Commands,
+    mut trns_event_writer: EventWriter<SceneTransitionEvent>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    pending: Option<ResMut<PendingLevel>>,
+) {
+    let mut pending = match pending {
+        Some(pending) => pending,
+        None => return,
+    };
+
+    let result = match future::block_on(future::poll_once(&mut pending.0)) {
+        Some(result) => result,
+        None => return,
+    };
+
+    commands.remove_resource::<PendingLevel>();
+
+    match result {
+        Ok(GeneratedLevel::Random(random)) => commands.insert_resource(random),
+        Ok(GeneratedLevel::Daily(daily)) => commands.insert_resource(daily),
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    }
+
+    trns_event_writer.send(SceneTransitionEvent::new(
+        TransitionEffect::Fade,
+        AppState::Game,
+    ));
+}
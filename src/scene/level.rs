@@ -1,9 +1,13 @@
 use crate::{
-    game::{ufo::UFO, GameState},
+    asset::{watch::FileWatcher, GameAssetServer},
+    error::{self, GameError, GameResult},
+    game::{record::LevelRecords, ufo::UFO, GameState},
+    generator::{daily::DailyChallenge, RandomLevel},
     object::asset::ObjectAssetServer,
-    object::{Object, ObjectDesc},
+    object::{self, Object, ObjectDesc, ObjectID},
+    scene::{editor::EditorLevel, error::GameErrorEvent},
     state::AppState,
-    ui::game_ui::GameUINumberValue,
+    ui::game_ui::{GameUINumberValue, HudWidget},
     world::{
         self,
         grid::Grid,
@@ -12,20 +16,47 @@ use crate::{
 };
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub struct LevelPlugin;
 
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, setup_resources)
+        app.register_type::<Level>()
+            .register_type::<Score>()
+            .register_type::<TurnCounter>()
+            .add_event::<ReloadLevelEvent>()
+            .add_event::<ScoreEvent>()
+            .add_systems(PreStartup, setup_resources)
             .add_systems(OnEnter(AppState::Game), load_level)
-            .add_systems(OnExit(AppState::Game), unload_level);
+            .add_systems(OnExit(AppState::Game), unload_level)
+            .add_systems(
+                Update,
+                (watch_level_file, handle_score_event).run_if(in_state(AppState::Game)),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    reset_level_progress,
+                    unload_level,
+                    apply_deferred,
+                    load_level,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Game).and_then(on_event::<ReloadLevelEvent>())),
+            );
     }
 }
 
 /************************************************************
  * - Constants
  */
+const DEFAULT_UFO_START: (i32, i32) = (1, 6);
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+const FNV_PRIME: u64 = 0x100000001b3;
+
 const LEVEL_PATHS: [&str; 10] = [
     "assets/scn/level_0.ron",
     "assets/scn/level_1.ron",
@@ -43,12 +74,132 @@ const LEVEL_PATHS: [&str; 10] = [
  * - Types
  */
 
-#[derive(Debug, Serialize, Deserialize)]
-struct LevelDesc {
-    objects: Vec<ObjectDesc>,
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LevelDesc {
+    pub objects: Vec<ObjectDesc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid: Option<Vec<usize>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ufo: Option<UVec2>,
+    // Widgets shown on top of the HUD layout while the level is played
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hud: Vec<HudWidget>,
 }
 
-#[derive(Debug, Resource)]
+impl LevelDesc {
+    pub fn new() -> Self {
+        Self {
+            objects: vec![],
+            grid: None,
+            ufo: None,
+            hud: vec![],
+        }
+    }
+
+    pub fn load(path: &str) -> GameResult<Self> {
+        return error::read_ron(path);
+    }
+
+    pub fn save(&self, path: &str) -> GameResult<()> {
+        return error::write_ron(path, self);
+    }
+
+    // Levels without a mask use the default board shape
+    pub fn mask(&self, grid: &Grid) -> Vec<usize> {
+        return match &self.grid {
+            Some(mask) => mask.clone(),
+            None => Grid::default_mask(grid.size),
+        };
+    }
+
+    pub fn ufo_start(&self) -> IVec2 {
+        let start = self.ufo.unwrap_or(UVec2::new(
+            DEFAULT_UFO_START.0 as u32,
+            DEFAULT_UFO_START.1 as u32,
+        ));
+
+        return IVec2::new(start.x as i32, start.y as i32);
+    }
+
+    // FNV-1a hash of the serialized level, identical levels share the same checksum
+    pub fn checksum(&self) -> u64 {
+        let contents = ron::to_string(self).unwrap_or_default();
+
+        let mut hash: u64 = FNV_OFFSET_BASIS;
+        for byte in contents.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+
+        return hash;
+    }
+
+    pub fn validate(&self, grid: &Grid, oas: &ObjectAssetServer) -> GameResult<()> {
+        // Every level requires exactly one King and one Castle
+        for id in [ObjectID::King, ObjectID::Castle] {
+            match self.objects.iter().filter(|od| od.id == id).count() {
+                0 => return Err(GameError::MissingObject(id)),
+                1 => {}
+                _ => return Err(GameError::DuplicateObject(id)),
+            }
+        }
+
+        return self.validate_placement(grid, oas);
+    }
+
+    // Checks everything except the required Objects, which an unfinished level may still lack
+    pub fn validate_placement(&self, grid: &Grid, oas: &ObjectAssetServer) -> GameResult<()> {
+        let mask = self.mask(grid);
+        if mask.len() != (grid.size.0 * grid.size.1) as usize {
+            return Err(GameError::InvalidGridMask(mask.len()));
+        }
+
+        let index = |position: IVec2| ((position.y * grid.size.0 as i32) + position.x) as usize;
+
+        let ufo = self.ufo_start();
+        if !grid.contains(ufo) || mask[index(ufo)] == 0 {
+            return Err(GameError::InvalidUFOStart(ufo));
+        }
+
+        let mut occupied = vec![false; mask.len()];
+
+        for od in &self.objects {
+            let asset = oas.get(od.id)?;
+
+            for cell in object::occupied_cells(od.position, asset.conf.occupy(od.orientation)) {
+                if !grid.contains(cell) || mask[index(cell)] == 0 {
+                    return Err(GameError::InvalidPosition {
+                        id: od.id,
+                        position: cell,
+                    });
+                }
+
+                if occupied[index(cell)] {
+                    return Err(GameError::OverlappingObjects {
+                        id: od.id,
+                        position: cell,
+                    });
+                }
+
+                occupied[index(cell)] = true;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[derive(Debug, Default, Event)]
+pub struct ReloadLevelEvent;
+
+impl ReloadLevelEvent {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct TurnCounter {
     pub turn: usize,
 }
@@ -59,16 +210,65 @@ impl TurnCounter {
     }
 }
 
+impl Default for TurnCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GameUINumberValue for TurnCounter {
     fn value(&self) -> usize {
         return self.turn;
     }
 }
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+pub enum ScoreReason {
+    Housed,
+    Farmed,
+    TurnBonus,
+}
+
+impl ScoreReason {
+    pub fn label(&self) -> &'static str {
+        return match self {
+            Self::Housed => "Villagers housed",
+            Self::Farmed => "Cows farmed",
+            Self::TurnBonus => "Turn bonus",
+        };
+    }
+}
+
+#[derive(Debug, Event)]
+pub struct ScoreEvent {
+    reason: ScoreReason,
+    amount: usize,
+}
+
+impl ScoreEvent {
+    pub fn new(reason: ScoreReason, amount: usize) -> Self {
+        Self { reason, amount }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct ScoreEntry {
+    pub reason: ScoreReason,
+    pub amount: usize,
+}
+
+#[derive(Debug, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct Score {
     pub previous: usize,
     pub current: usize,
+    // Contributions made during the current level
+    pub entries: Vec<ScoreEntry>,
+    // Points per reason from every completed level
+    #[reflect(ignore)]
+    totals: BTreeMap<ScoreReason, usize>,
+    // Level being played is part of the campaign, other levels don't add to the totals
+    campaign: bool,
 }
 
 impl Score {
@@ -76,7 +276,59 @@ impl Score {
         Self {
             previous: 0,
             current: 0,
+            entries: vec![],
+            totals: BTreeMap::new(),
+            campaign: false,
+        }
+    }
+
+    fn add(&mut self, reason: ScoreReason, amount: usize) {
+        self.current += amount;
+        self.entries.push(ScoreEntry { reason, amount });
+    }
+
+    // Discards everything scored since the level started
+    pub fn reset(&mut self) {
+        self.current = self.previous;
+        self.entries.clear();
+    }
+
+    fn commit(&mut self) {
+        for entry in self.entries.drain(..) {
+            *self.totals.entry(entry.reason).or_default() += entry.amount;
         }
+
+        self.previous = self.current;
+    }
+
+    // Number of contributions and points per reason for the current level
+    pub fn breakdown(&self) -> BTreeMap<ScoreReason, (usize, usize)> {
+        let mut breakdown = BTreeMap::new();
+
+        for entry in &self.entries {
+            let (count, amount) = breakdown.entry(entry.reason).or_insert((0, 0));
+            *count += 1;
+            *amount += entry.amount;
+        }
+
+        return breakdown;
+    }
+
+    // Points per reason over the whole run, including the current level
+    pub fn totals(&self) -> BTreeMap<ScoreReason, usize> {
+        let mut totals = self.totals.clone();
+
+        for entry in &self.entries {
+            *totals.entry(entry.reason).or_default() += entry.amount;
+        }
+
+        return totals;
+    }
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -87,23 +339,46 @@ impl GameUINumberValue for Score {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Resource)]
+#[derive(Debug, Default, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct Level {
     pub current: usize,
     pub maximum: usize,
+    // Checksum of the level being played, wherever it was loaded from
+    pub checksum: u64,
+    // Extra HUD widgets of the level being played
+    #[reflect(ignore)]
+    pub hud: Vec<HudWidget>,
 }
 
 impl Level {
-    fn new(current: usize) -> Self {
+    fn new(current: usize) -> GameResult<Self> {
         let maximum = LEVEL_PATHS.len();
         if current >= maximum {
-            panic!("Supplied invalid Level number at startup.");
+            return Err(GameError::InvalidLevel(current));
         }
 
-        Self { current, maximum }
+        Ok(Self {
+            current,
+            maximum,
+            checksum: 0,
+            hud: vec![],
+        })
     }
 
-    pub fn next(&mut self) -> AppState {
+    pub fn path(&self) -> &'static str {
+        return LEVEL_PATHS[self.current];
+    }
+
+    // Checksums of the campaign levels, None for a level that fails to load
+    pub fn checksums() -> Vec<Option<u64>> {
+        return LEVEL_PATHS
+            .iter()
+            .map(|path| LevelDesc::load(path).ok().map(|desc| desc.checksum()))
+            .collect();
+    }
+
+    pub fn advance(&mut self) -> AppState {
         self.current += 1;
         if self.current >= self.maximum {
             self.current = self.maximum - 1;
@@ -124,66 +399,153 @@ impl GameUINumberValue for Level {
 /************************************************************
  * - System Functions */
 
-fn setup_resources(mut commands: Commands) {
-    commands.insert_resource(Level::new(5));
+fn setup_resources(mut commands: Commands, mut error_writer: EventWriter<GameErrorEvent>) {
+    let level = match Level::new(5) {
+        Ok(level) => level,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+
+            Level {
+                current: 0,
+                maximum: LEVEL_PATHS.len(),
+                checksum: 0,
+                hud: vec![],
+            }
+        }
+    };
+
+    commands.insert_resource(level);
     commands.insert_resource(Score::new());
     commands.insert_resource(TurnCounter::new());
 }
 
-fn load_level(
+pub fn load_level(
     mut commands: Commands,
     mut event_writer: EventWriter<TileStateChangeEvent>,
+    mut error_writer: EventWriter<GameErrorEvent>,
     mut world: ResMut<world::World>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     mut turn_counter: ResMut<TurnCounter>,
     mut score: ResMut<Score>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut grid: ResMut<Grid>,
     oas: Res<ObjectAssetServer>,
-    asset_server: Res<AssetServer>,
-    level: Res<Level>,
-    grid: Res<Grid>,
+    gas: Res<GameAssetServer>,
+    mut level: ResMut<Level>,
+    editor: Option<Res<EditorLevel>>,
+    random: Option<Res<RandomLevel>>,
+    daily: Option<Res<DailyChallenge>>,
 ) {
-    let level_desc: LevelDesc =
-        if let Ok(contents) = std::fs::read_to_string(LEVEL_PATHS[level.current]) {
-            ron::from_str(&contents).unwrap()
-        } else {
-            panic!(
-                "Failed to load LevelDesc from, `{}`.",
-                LEVEL_PATHS[level.current]
-            );
-        };
+    let campaign = !editor.as_ref().is_some_and(|editor| editor.playing)
+        && random.is_none()
+        && daily.is_none();
 
-    // Set GameState to active
-    game_state.set(GameState::PlayerControlled);
+    // Test-play the level being edited, or play a generated one instead of the campaign
+    let level_desc = match (editor, random, daily) {
+        (Some(editor), _, _) if editor.playing => Ok(editor.desc.clone()),
+        (_, Some(random), _) => Ok(random.desc.clone()),
+        (_, _, Some(daily)) => Ok(daily.desc.clone()),
+        _ => LevelDesc::load(level.path()),
+    };
 
-    // Reset TurnCounter
-    turn_counter.turn = 0;
+    let level_desc = match level_desc.and_then(|desc| desc.validate(&grid, &oas).map(|_| desc)) {
+        Ok(level_desc) => level_desc,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    };
 
-    // Set Score
-    score.previous = score.current;
+    grid.grid = level_desc.mask(&grid);
+    level.checksum = level_desc.checksum();
+    level.hud = level_desc.hud.clone();
 
-    world::generate_tiles(&grid, &mut commands);
-
-    world::generate_objects(
+    if let Err(e) = world::generate_objects(
         &level_desc.objects,
         &grid,
         &oas,
         &mut texture_atlases,
         &mut world,
         &mut commands,
-    );
+    ) {
+        error_writer.send(GameErrorEvent::new(e));
+        return;
+    }
+
+    // Set GameState to active
+    game_state.set(GameState::PlayerControlled);
+
+    // Reset TurnCounter
+    turn_counter.turn = 0;
 
-    UFO::new(
-        IVec2::new(1, 6),
+    // Keep the points of the previous level only when it was a campaign level
+    if score.campaign {
+        score.commit();
+    } else {
+        score.reset();
+    }
+    score.campaign = campaign;
+
+    world::generate_tiles(&grid, &mut commands);
+
+    if let Err(e) = UFO::new(
+        level_desc.ufo_start(),
         &grid,
-        &asset_server,
+        &gas,
         &mut commands,
-        &mut texture_atlases,
         &mut event_writer,
-    );
+    ) {
+        error_writer.send(GameErrorEvent::new(e));
+    }
+}
+
+fn watch_level_file(
+    mut watcher: Local<Option<FileWatcher>>,
+    mut event_writer: EventWriter<ReloadLevelEvent>,
+    mut records: ResMut<LevelRecords>,
+    level: Res<Level>,
+    time: Res<Time>,
+) {
+    let path = level.path();
+
+    // Start watching the new file when the level changes
+    if watcher
+        .as_ref()
+        .is_none_or(|watcher| watcher.path() != path)
+    {
+        *watcher = Some(FileWatcher::new(path));
+        return;
+    }
+
+    let watcher = watcher.as_mut().unwrap();
+
+    if !watcher.changed(time.delta()) {
+        return;
+    }
+
+    // Don't reload a level file that is still being edited into a valid state
+    match LevelDesc::load(path) {
+        Ok(desc) => {
+            records.update_checksum(level.current, desc.checksum());
+            event_writer.send(ReloadLevelEvent::new());
+
+            info!("Reloaded `{}`.", path);
+        }
+        Err(e) => error!("{}", e),
+    }
+}
+
+fn reset_level_progress(mut score: ResMut<Score>) {
+    score.reset();
+}
+
+fn handle_score_event(mut event_reader: EventReader<ScoreEvent>, mut score: ResMut<Score>) {
+    for event in event_reader.iter() {
+        score.add(event.reason, event.amount);
+    }
 }
 
-fn unload_level(
+pub fn unload_level(
     mut commands: Commands,
     tilemap: Query<Entity, With<TileMap>>,
     objects: Query<Entity, With<Object>>,
@@ -0,0 +1,171 @@
+use crate::{
+    game::record::LevelRecords,
+    global::window,
+    render::{RenderLayer, RENDER_LAYER},
+    scene::level::Level,
+    settings::Settings,
+    state::transition::{SceneTransitionEvent, TransitionEffect, TransitionPayload},
+    state::AppState,
+    ui::bitmap_text::{BitmapText, BitmapTextSystem},
+};
+use bevy::{prelude::*, sprite::Anchor};
+
+pub struct LevelSelectPlugin;
+
+impl Plugin for LevelSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::LevelSelect), load_level_select)
+            .add_systems(OnExit(AppState::LevelSelect), unload_level_select)
+            .add_systems(
+                Update,
+                control_level_select.run_if(in_state(AppState::LevelSelect)),
+            )
+            .add_systems(
+                PostUpdate,
+                update_level_select_text
+                    .before(BitmapTextSystem)
+                    .run_if(in_state(AppState::LevelSelect)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const LEVEL_SELECT_MARGIN: f32 = 8.0;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Component)]
+struct LevelSelect {
+    selected: usize,
+}
+
+#[derive(Debug, Component)]
+struct LevelSelectText;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_level_select(mut commands: Commands, level: Res<Level>) {
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            LevelSelect {
+                selected: level.current,
+            },
+            Name::new("Level Select"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    LEVEL_SELECT_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - LEVEL_SELECT_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(""),
+                LevelSelectText,
+            ));
+        });
+}
+
+fn unload_level_select(mut commands: Commands, query: Query<Entity, With<LevelSelect>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_level_select(
+    mut query: Query<&mut LevelSelect>,
+    mut event_writer: EventWriter<SceneTransitionEvent>,
+    level: Res<Level>,
+    settings: Res<Settings>,
+    keys: Res<Input<KeyCode>>,
+) {
+    let mut select = match query.get_single_mut() {
+        Ok(select) => select,
+        Err(_) => return,
+    };
+
+    let input = &settings.input;
+
+    if keys.just_pressed(input.up) && select.selected > 0 {
+        select.selected -= 1;
+    }
+
+    if keys.just_pressed(input.down) && select.selected < level.maximum - 1 {
+        select.selected += 1;
+    }
+
+    if keys.just_pressed(input.lift) {
+        event_writer.send(
+            SceneTransitionEvent::new(TransitionEffect::Fade, AppState::Game)
+                .with_payload(TransitionPayload::Level(select.selected)),
+        );
+    }
+
+    if keys.just_pressed(input.settings) {
+        event_writer.send(SceneTransitionEvent::new(
+            TransitionEffect::Fade,
+            AppState::Title,
+        ));
+    }
+}
+
+fn update_level_select_text(
+    select_query: Query<&LevelSelect, Changed<LevelSelect>>,
+    mut text_query: Query<&mut BitmapText, With<LevelSelectText>>,
+    records: Res<LevelRecords>,
+) {
+    let select = match select_query.get_single() {
+        Ok(select) => select,
+        Err(_) => return,
+    };
+
+    let mut contents = String::from("LEVEL SELECT\n\n");
+
+    for (i, checksum) in records.checksums().iter().enumerate() {
+        let cursor = if i == select.selected { ">" } else { " " };
+
+        contents += &match checksum.and_then(|checksum| records.get(checksum)) {
+            Some(record) => format!(
+                "{} {:>2}  {:>3} pts  {:>2} turns\n",
+                cursor,
+                i + 1,
+                record.score,
+                record.turns
+            ),
+            None => format!("{} {:>2}  ---\n", cursor, i + 1),
+        };
+    }
+
+    // Completion date of the selected level
+    let selected = records.checksums()[select.selected].and_then(|checksum| records.get(checksum));
+    if let Some(record) = selected {
+        contents += &format!("\nBest on {}", record.date);
+    }
+
+    for mut text in &mut text_query {
+        text.text = contents.clone();
+    }
+}
@@ -1,25 +1,29 @@
-use crate::state::AppState;
 use bevy::prelude::*;
 
+pub mod daily;
+pub mod editor;
 pub mod end;
+pub mod error;
+pub mod generating;
 pub mod level;
+pub mod level_select;
+pub mod preload;
 pub mod splash;
+pub mod title;
 
 pub struct ScenePlugin;
 
 impl Plugin for ScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(splash::SplashPlugin)
+        app.add_plugins(preload::PreloadPlugin)
+            .add_plugins(splash::SplashPlugin)
+            .add_plugins(title::TitlePlugin)
             .add_plugins(level::LevelPlugin)
+            .add_plugins(level_select::LevelSelectPlugin)
+            .add_plugins(daily::DailyResultPlugin)
+            .add_plugins(generating::GeneratingPlugin)
+            .add_plugins(editor::EditorPlugin)
             .add_plugins(end::EndPlugin)
-            .add_systems(PostStartup, set_initial_scene);
+            .add_plugins(error::ErrorPlugin);
     }
 }
-
-/************************************************************
- * - System Functions
- */
-
-fn set_initial_scene(mut app_state: ResMut<NextState<AppState>>) {
-    app_state.set(AppState::Splash);
-}
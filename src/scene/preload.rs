@@ -0,0 +1,233 @@
+use crate::{
+    asset::GameAssetServer,
+    global::window,
+    object::asset::ObjectAssetServer,
+    render::{RenderLayer, RENDER_LAYER},
+    state::AppState,
+    ui::bitmap_text::BitmapText,
+};
+use bevy::{asset::LoadState, prelude::*, sprite::Anchor};
+
+pub struct PreloadPlugin;
+
+impl Plugin for PreloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Preload), load_preload_scene)
+            .add_systems(OnExit(AppState::Preload), unload_preload_scene)
+            .add_systems(
+                Update,
+                update_preload_progress.run_if(in_state(AppState::Preload)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const PRELOAD_BAR_SIZE: (f32, f32) = (80.0, 4.0);
+
+const PRELOAD_BAR_BORDER: f32 = 1.0;
+
+const PRELOAD_ERROR_MARGIN: f32 = 8.0;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Component)]
+struct PreloadScene {
+    failed: bool,
+}
+
+impl PreloadScene {
+    fn new() -> Self {
+        Self { failed: false }
+    }
+}
+
+#[derive(Debug, Component)]
+struct PreloadProgressBar;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_preload_scene(mut commands: Commands) {
+    let origin = Vec2::new(
+        (window::VIEWPORT_RESOLUTION.0 as f32 - PRELOAD_BAR_SIZE.0) / 2.0,
+        (window::VIEWPORT_RESOLUTION.1 as f32 - PRELOAD_BAR_SIZE.1) / 2.0,
+    );
+
+    let border = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    origin.x - PRELOAD_BAR_BORDER,
+                    origin.y - PRELOAD_BAR_BORDER,
+                    0.0,
+                ),
+                sprite: Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(Vec2::new(
+                        PRELOAD_BAR_SIZE.0 + PRELOAD_BAR_BORDER * 2.0,
+                        PRELOAD_BAR_SIZE.1 + PRELOAD_BAR_BORDER * 2.0,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Name::new("Preload Progress Border"),
+        ))
+        .id();
+
+    let background = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(origin.x, origin.y, 1.0),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(PRELOAD_BAR_SIZE.0, PRELOAD_BAR_SIZE.1)),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Name::new("Preload Progress Background"),
+        ))
+        .id();
+
+    let bar = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(origin.x, origin.y, 2.0),
+                sprite: Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(Vec2::new(0.0, PRELOAD_BAR_SIZE.1)),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            PreloadProgressBar,
+            Name::new("Preload Progress Bar"),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(
+                0.0,
+                0.0,
+                RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+            )),
+            PreloadScene::new(),
+            Name::new("Preload Scene"),
+        ))
+        .push_children(&[border, background, bar]);
+}
+
+fn unload_preload_scene(mut commands: Commands, query: Query<Entity, With<PreloadScene>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn update_preload_progress(
+    mut commands: Commands,
+    mut scene_query: Query<(Entity, &mut PreloadScene)>,
+    mut bar_query: Query<&mut Sprite, With<PreloadProgressBar>>,
+    mut app_state: ResMut<NextState<AppState>>,
+    asset_server: Res<AssetServer>,
+    gas: Res<GameAssetServer>,
+    oas: Res<ObjectAssetServer>,
+) {
+    let (entity, mut scene) = match scene_query.get_single_mut() {
+        Ok(scene) => scene,
+        Err(_) => return,
+    };
+
+    if scene.failed {
+        return;
+    }
+
+    let handles: Vec<_> = gas.handles().into_iter().chain(oas.handles()).collect();
+
+    let mut loaded = 0;
+    let mut failed = vec![];
+
+    for (path, handle) in &handles {
+        match asset_server.get_load_state(*handle) {
+            LoadState::Loaded => loaded += 1,
+            LoadState::Failed => failed.push(*path),
+            _ => {}
+        }
+    }
+
+    // Report every asset that failed instead of continuing with missing sprites
+    if !failed.is_empty() {
+        scene.failed = true;
+
+        for path in &failed {
+            error!("Failed to load asset, `{}`.", path);
+        }
+
+        let error = spawn_preload_error(&failed, &mut commands);
+        commands.entity(entity).add_child(error);
+
+        return;
+    }
+
+    for mut sprite in &mut bar_query {
+        let progress = loaded as f32 / handles.len().max(1) as f32;
+
+        sprite.custom_size = Some(Vec2::new(PRELOAD_BAR_SIZE.0 * progress, PRELOAD_BAR_SIZE.1));
+    }
+
+    if loaded == handles.len() {
+        app_state.set(AppState::Splash);
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn spawn_preload_error(failed: &Vec<&str>, commands: &mut Commands) -> Entity {
+    let mut contents = String::from("Failed to load assets:\n\n");
+    for path in failed {
+        contents += &format!("{}\n", path);
+    }
+
+    return commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0.0, 0.0, 3.0),
+                sprite: Sprite {
+                    color: Color::BLACK,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Name::new("Preload Error"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    PRELOAD_ERROR_MARGIN,
+                    window::VIEWPORT_RESOLUTION.1 as f32 - PRELOAD_ERROR_MARGIN,
+                    1.0,
+                )),
+                BitmapText::new(&contents).with_width(
+                    window::VIEWPORT_RESOLUTION.0 as u32 - PRELOAD_ERROR_MARGIN as u32 * 2,
+                ),
+            ));
+        })
+        .id();
+}
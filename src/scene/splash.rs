@@ -1,7 +1,8 @@
 use crate::{
     animation::{Animate, AnimationMode},
-    global::window,
+    asset::GameAssetServer,
     render::{RenderLayer, RENDER_LAYER},
+    scene::error::GameErrorEvent,
     state::transition::{SceneTransitionEvent, TransitionEffect},
     state::AppState,
 };
@@ -24,13 +25,6 @@ impl Plugin for SplashPlugin {
  * - Constants
  */
 
-const SPLASH_TEXTURE_ATLAS_PATH: &str = "ui/gbjam_intro.png";
-
-const SPLASH_TEXTURE_ATLAS_TILE: (usize, usize) =
-    (window::VIEWPORT_RESOLUTION.0, window::VIEWPORT_RESOLUTION.1);
-
-const SPLASH_TEXTURE_ATLAS_SIZE: (usize, usize) = (8, 1);
-
 const SPLASH_ANIMATION_INTERVAL: f32 = 0.1;
 
 const SPLASH_ANIMATION_DURATION: f32 = 2.0;
@@ -64,7 +58,8 @@ fn update_splash_animation(
     let mut splash = match query.get_single_mut() {
         Ok(s) => s,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple SplashAnimations are present.");
+            error!("Multiple SplashAnimations are present.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => {
             return;
@@ -76,32 +71,28 @@ fn update_splash_animation(
     if splash.timer.just_finished() {
         event_writer.send(SceneTransitionEvent::new(
             TransitionEffect::WhiteFade,
-            AppState::Game,
+            AppState::Title,
         ));
     }
 }
 
 fn load_splash_scene(
     mut commands: Commands,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    asset_server: Res<AssetServer>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
 ) {
-    let texture_atlas = TextureAtlas::from_grid(
-        asset_server.load(SPLASH_TEXTURE_ATLAS_PATH),
-        Vec2::new(
-            SPLASH_TEXTURE_ATLAS_TILE.0 as f32,
-            SPLASH_TEXTURE_ATLAS_TILE.1 as f32,
-        ),
-        SPLASH_TEXTURE_ATLAS_SIZE.0,
-        SPLASH_TEXTURE_ATLAS_SIZE.1,
-        None,
-        None,
-    );
+    let asset = match gas.atlas("splash") {
+        Ok(asset) => asset,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    };
 
     commands.spawn((
         SpriteSheetBundle {
             transform: Transform::from_xyz(0.0, 0.0, RENDER_LAYER[RenderLayer::UI as usize] as f32),
-            texture_atlas: texture_atlases.add(texture_atlas),
+            texture_atlas: asset.handle.clone(),
             sprite: TextureAtlasSprite {
                 index: 0,
                 anchor: bevy::sprite::Anchor::BottomLeft,
@@ -110,7 +101,7 @@ fn load_splash_scene(
             ..Default::default()
         },
         Animate::new(
-            SPLASH_TEXTURE_ATLAS_SIZE.0,
+            asset.desc.frame_count(),
             SPLASH_ANIMATION_INTERVAL,
             AnimationMode::Loop,
         ),
@@ -0,0 +1,307 @@
+use crate::{
+    asset::GameAssetServer,
+    error::GameResult,
+    generator::{GeneratorConf, PendingLevel},
+    object::asset::ObjectAssetServer,
+    render::{RenderLayer, RENDER_LAYER},
+    scene::{error::GameErrorEvent, level::Score},
+    settings::Settings,
+    state::transition::{SceneTransitionEvent, TransitionEffect},
+    state::AppState,
+    ui::bitmap_text::{BitmapText, BITMAP_TEXT_DEFAULT_FONT},
+};
+use bevy::{app::AppExit, prelude::*, sprite::Anchor};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct TitlePlugin;
+
+impl Plugin for TitlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Title), load_title_scene)
+            .add_systems(OnExit(AppState::Title), unload_title_scene)
+            .add_systems(
+                Update,
+                (control_title_scene, update_title_cursor)
+                    .chain()
+                    .run_if(in_state(AppState::Title)),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const TITLE_OPTIONS: [TitleOption; 4] = [
+    TitleOption::Start,
+    TitleOption::Daily,
+    TitleOption::Random,
+    TitleOption::Quit,
+];
+
+const TITLE_UFO_POSITION: (f32, f32) = (35.0, 96.0);
+
+const TITLE_OPTION_POSITIONS: [(f32, f32); 4] =
+    [(62.0, 62.0), (62.0, 51.0), (60.0, 41.0), (66.0, 28.0)];
+
+const RANDOM_LEVEL_PROFILE: &str = "normal";
+
+const TITLE_CURSOR_OFFSET: (f32, f32) = (-8.0, 2.0);
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Copy)]
+enum TitleOption {
+    Start,
+    Daily,
+    Random,
+    Quit,
+}
+
+impl TitleOption {
+    // Options without an image are drawn as text
+    fn asset_id(&self) -> Option<&'static str> {
+        return match self {
+            Self::Start => Some("title_start"),
+            Self::Daily | Self::Random => None,
+            Self::Quit => Some("title_quit"),
+        };
+    }
+
+    fn label(&self) -> &'static str {
+        return match self {
+            Self::Start => "START",
+            Self::Daily => "DAILY",
+            Self::Random => "RANDOM",
+            Self::Quit => "QUIT",
+        };
+    }
+}
+
+#[derive(Debug, Component)]
+struct TitleScene {
+    selected: usize,
+}
+
+impl TitleScene {
+    fn new() -> Self {
+        Self { selected: 0 }
+    }
+}
+
+#[derive(Debug, Component)]
+struct TitleCursor;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_title_scene(
+    mut commands: Commands,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
+) {
+    if let Err(e) = spawn_title_scene(&gas, &mut commands) {
+        error_writer.send(GameErrorEvent::new(e));
+    }
+}
+
+fn unload_title_scene(mut commands: Commands, query: Query<Entity, With<TitleScene>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_title_scene(
+    mut commands: Commands,
+    mut query: Query<&mut TitleScene>,
+    mut trns_event_writer: EventWriter<SceneTransitionEvent>,
+    mut exit_event_writer: EventWriter<AppExit>,
+    settings: Res<Settings>,
+    conf: Res<GeneratorConf>,
+    oas: Res<ObjectAssetServer>,
+    keys: Res<Input<KeyCode>>,
+) {
+    let mut scene = match query.get_single_mut() {
+        Ok(scene) => scene,
+        Err(_) => return,
+    };
+
+    let input = &settings.input;
+
+    if keys.just_pressed(input.up) && scene.selected > 0 {
+        scene.selected -= 1;
+    }
+
+    if keys.just_pressed(input.down) && scene.selected < TITLE_OPTIONS.len() - 1 {
+        scene.selected += 1;
+    }
+
+    if keys.just_pressed(input.lift) {
+        match TITLE_OPTIONS[scene.selected] {
+            TitleOption::Start => {
+                // Every campaign run starts from zero
+                commands.insert_resource(Score::default());
+
+                trns_event_writer.send(SceneTransitionEvent::new(
+                    TransitionEffect::Fade,
+                    AppState::LevelSelect,
+                ));
+            }
+            TitleOption::Daily => {
+                commands.insert_resource(PendingLevel::daily(&conf, &oas));
+
+                trns_event_writer.send(SceneTransitionEvent::new(
+                    TransitionEffect::Fade,
+                    AppState::Generating,
+                ));
+            }
+            TitleOption::Random => {
+                let seed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+
+                commands.insert_resource(PendingLevel::random(
+                    seed,
+                    RANDOM_LEVEL_PROFILE,
+                    &conf,
+                    &oas,
+                ));
+
+                trns_event_writer.send(SceneTransitionEvent::new(
+                    TransitionEffect::Fade,
+                    AppState::Generating,
+                ));
+            }
+            TitleOption::Quit => exit_event_writer.send(AppExit),
+        }
+    }
+}
+
+fn update_title_cursor(
+    scene_query: Query<&TitleScene, Changed<TitleScene>>,
+    mut cursor_query: Query<&mut Transform, With<TitleCursor>>,
+) {
+    let scene = match scene_query.get_single() {
+        Ok(scene) => scene,
+        Err(_) => return,
+    };
+
+    let position = cursor_position(scene.selected);
+
+    for mut transform in &mut cursor_query {
+        transform.translation.x = position.0;
+        transform.translation.y = position.1;
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn cursor_position(selected: usize) -> (f32, f32) {
+    let option = TITLE_OPTION_POSITIONS[selected];
+
+    return (
+        option.0 + TITLE_CURSOR_OFFSET.0,
+        option.1 + TITLE_CURSOR_OFFSET.1,
+    );
+}
+
+// Every asset is looked up before spawning, so a missing one leaves nothing behind
+fn spawn_title_scene(gas: &GameAssetServer, commands: &mut Commands) -> GameResult<()> {
+    let ufo = gas.texture("title_ufo")?.handle.clone();
+    let cursor = gas.texture("title_cursor")?.handle.clone();
+    let screen = gas.texture("title_screen")?.handle.clone();
+
+    // Text hangs below its position, line it up with the images instead
+    let height = gas.font(BITMAP_TEXT_DEFAULT_FONT)?.desc.line_height as f32;
+
+    let mut options = vec![];
+    for option in TITLE_OPTIONS {
+        options.push(match option.asset_id() {
+            Some(id) => Some(gas.texture(id)?.handle.clone()),
+            None => None,
+        });
+    }
+
+    let mut children = vec![];
+
+    children.push(spawn_title_image(
+        ufo,
+        TITLE_UFO_POSITION,
+        "Title UFO",
+        commands,
+    ));
+
+    for ((option, texture), position) in TITLE_OPTIONS
+        .iter()
+        .zip(options)
+        .zip(TITLE_OPTION_POSITIONS)
+    {
+        children.push(match texture {
+            Some(texture) => spawn_title_image(texture, position, "Title Option", commands),
+            None => spawn_title_text(option.label(), (position.0, position.1 + height), commands),
+        });
+    }
+
+    let cursor = spawn_title_image(cursor, cursor_position(0), "Title Cursor", commands);
+    commands.entity(cursor).insert(TitleCursor);
+    children.push(cursor);
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::UI as usize] as f32,
+                ),
+                texture: screen,
+                sprite: Sprite {
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            TitleScene::new(),
+            Name::new("Title Scene"),
+        ))
+        .push_children(&children);
+
+    return Ok(());
+}
+
+fn spawn_title_image(
+    texture: Handle<Image>,
+    position: (f32, f32),
+    name: &str,
+    commands: &mut Commands,
+) -> Entity {
+    return commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(position.0, position.1, 1.0),
+                texture,
+                sprite: Sprite {
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            Name::new(name.to_string()),
+        ))
+        .id();
+}
+
+fn spawn_title_text(label: &str, position: (f32, f32), commands: &mut Commands) -> Entity {
+    return commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(position.0, position.1, 1.0)),
+            BitmapText::new(label),
+            Name::new("Title Option"),
+        ))
+        .id();
+}
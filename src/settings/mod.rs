@@ -0,0 +1,304 @@
+use crate::{
+    audio::AudioBus,
+    error::{self, GameError},
+    global,
+};
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowMode, WindowResizeConstraints},
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SettingsLoadError>()
+            .add_systems(Startup, report_settings_load_error)
+            .add_systems(Update, toggle_fullscreen)
+            .add_systems(PostUpdate, apply_window_settings);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const SETTINGS_DIRECTORY: &str = "alien-architect";
+
+const SETTINGS_FILE: &str = "settings.ron";
+
+pub const SCALE_RANGE: (usize, usize) = (1, 8);
+
+pub const VOLUME_STEP: f32 = 0.1;
+
+pub const ANIMATION_SPEED_RANGE: (f32, f32) = (0.25, 3.0);
+
+pub const ANIMATION_SPEED_STEP: f32 = 0.25;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettings {
+    pub mode: WindowMode,
+    pub present_mode: PresentMode,
+    pub resizable: bool,
+    pub scale: usize,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            mode: global::window::MODE,
+            present_mode: global::window::PRESENT_MODE,
+            resizable: global::window::RESIZABLE,
+            scale: global::window::SCALE_FACTOR,
+        }
+    }
+}
+
+impl WindowSettings {
+    pub fn resolution(&self) -> (f32, f32) {
+        return (
+            (global::window::VIEWPORT_RESOLUTION.0 * self.scale) as f32,
+            (global::window::VIEWPORT_RESOLUTION.1 * self.scale) as f32,
+        );
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            music: 0.8,
+            sfx: 1.0,
+        }
+    }
+}
+
+impl AudioSettings {
+    pub fn volume(&self, bus: AudioBus) -> f32 {
+        return self.master
+            * match bus {
+                AudioBus::Music => self.music,
+                AudioBus::Sfx => self.sfx,
+            };
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputSettings {
+    pub up: KeyCode,
+    pub down: KeyCode,
+    pub left: KeyCode,
+    pub right: KeyCode,
+    pub lift: KeyCode,
+    pub cancel: KeyCode,
+    pub rotate: KeyCode,
+    pub restart: KeyCode,
+    pub settings: KeyCode,
+    pub fullscreen: KeyCode,
+}
+
+impl Default for InputSettings {
+    fn default() -> Self {
+        Self {
+            up: KeyCode::W,
+            down: KeyCode::S,
+            left: KeyCode::A,
+            right: KeyCode::D,
+            lift: KeyCode::H,
+            cancel: KeyCode::J,
+            rotate: KeyCode::K,
+            restart: KeyCode::Return,
+            settings: KeyCode::Escape,
+            fullscreen: KeyCode::F11,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameplaySettings {
+    pub animation_speed: f32,
+}
+
+impl Default for GameplaySettings {
+    fn default() -> Self {
+        Self {
+            animation_speed: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Resource, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub window: WindowSettings,
+    pub audio: AudioSettings,
+    pub input: InputSettings,
+    pub gameplay: GameplaySettings,
+}
+
+// Settings are loaded before the logger is running, the error is reported on startup
+#[derive(Debug, Default, Resource)]
+pub struct SettingsLoadError(pub Option<GameError>);
+
+impl Settings {
+    // Loads the settings file from the user config directory, if the file
+    // doesn't exist yet the defaults are written out in its place. A file that
+    // can't be read is left untouched and the defaults are used for this run.
+    pub fn load() -> (Self, SettingsLoadError) {
+        let path = match settings_path() {
+            Some(path) => path,
+            None => return (Self::default(), SettingsLoadError(None)),
+        };
+
+        if !path.exists() {
+            let settings = Self::default();
+            settings.save();
+
+            return (settings, SettingsLoadError(None));
+        }
+
+        return match error::read_ron::<Settings>(&path.to_string_lossy()) {
+            Ok(mut settings) => {
+                settings.clamp();
+                (settings, SettingsLoadError(None))
+            }
+            Err(e) => (Self::default(), SettingsLoadError(Some(e))),
+        };
+    }
+
+    pub fn save(&self) {
+        let path = match settings_path() {
+            Some(path) => path,
+            None => return,
+        };
+
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                error!("Failed to create `{}`: {}", parent.display(), e);
+                return;
+            }
+        }
+
+        if let Err(e) = error::write_ron(&path.to_string_lossy(), self) {
+            error!("{}", e);
+        }
+    }
+
+    pub fn window(&self) -> Window {
+        let resolution = self.window.resolution();
+
+        return Window {
+            resolution: bevy::window::WindowResolution::new(resolution.0, resolution.1),
+            title: format!("{} | {}", global::window::TITLE, global::app::PKG_VERSION),
+            resizable: self.window.resizable,
+            present_mode: self.window.present_mode,
+            mode: self.window.mode,
+            resize_constraints: WindowResizeConstraints {
+                min_width: global::window::VIEWPORT_RESOLUTION.0 as f32,
+                min_height: global::window::VIEWPORT_RESOLUTION.1 as f32,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+    }
+
+    fn clamp(&mut self) {
+        self.window.scale = self.window.scale.clamp(SCALE_RANGE.0, SCALE_RANGE.1);
+
+        self.audio.master = self.audio.master.clamp(0.0, 1.0);
+        self.audio.music = self.audio.music.clamp(0.0, 1.0);
+        self.audio.sfx = self.audio.sfx.clamp(0.0, 1.0);
+
+        self.gameplay.animation_speed = self
+            .gameplay
+            .animation_speed
+            .clamp(ANIMATION_SPEED_RANGE.0, ANIMATION_SPEED_RANGE.1);
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn apply_window_settings(
+    mut query: Query<&mut Window, With<PrimaryWindow>>,
+    mut previous: Local<Option<WindowSettings>>,
+    settings: Res<Settings>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    let mut window = match query.get_single_mut() {
+        Ok(window) => window,
+        Err(_) => return,
+    };
+
+    // The first run only records the settings the window is created with
+    let previous = match previous.replace(settings.window.clone()) {
+        Some(previous) => previous,
+        None => return,
+    };
+
+    if previous.mode != settings.window.mode {
+        window.mode = settings.window.mode;
+    }
+    if previous.present_mode != settings.window.present_mode {
+        window.present_mode = settings.window.present_mode;
+    }
+    if previous.resizable != settings.window.resizable {
+        window.resizable = settings.window.resizable;
+    }
+
+    // Only resize the window when the scale is changed, so a manually resized
+    // window is kept as it is while other settings are edited.
+    if previous.scale != settings.window.scale {
+        let resolution = settings.window.resolution();
+
+        window.resolution.set(resolution.0, resolution.1);
+    }
+}
+
+fn report_settings_load_error(mut load_error: ResMut<SettingsLoadError>) {
+    if let Some(e) = load_error.0.take() {
+        error!("{} Using the default settings.", e);
+    }
+}
+
+fn toggle_fullscreen(mut settings: ResMut<Settings>, keys: Res<Input<KeyCode>>) {
+    if !keys.just_pressed(settings.input.fullscreen) {
+        return;
+    }
+
+    settings.window.mode = match settings.window.mode {
+        WindowMode::Windowed => WindowMode::BorderlessFullscreen,
+        _ => WindowMode::Windowed,
+    };
+
+    settings.save();
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn settings_path() -> Option<PathBuf> {
+    return dirs::config_dir().map(|dir| dir.join(SETTINGS_DIRECTORY).join(SETTINGS_FILE));
+}
@@ -24,4 +24,9 @@ pub enum AppState {
     Game,
     End,
     Transition,
+    Error,
+    Editor,
+    DailyResult,
+    LevelSelect,
+    Generating,
 }
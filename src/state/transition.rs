@@ -1,10 +1,19 @@
 use crate::{
-    animation::{Animate, AnimationMode},
-    global,
+    asset::GameAssetServer,
+    error::GameResult,
+    global::window,
     render::{RenderLayer, RENDER_LAYER},
+    scene::{error::GameErrorEvent, level::Level},
     state::AppState,
 };
-use bevy::{ecs::query::QuerySingleError, prelude::*, sprite::Anchor};
+use bevy::{
+    ecs::query::QuerySingleError,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+    sprite::Anchor,
+};
+use std::collections::VecDeque;
+use std::fmt;
 
 pub struct TransitionPlugin;
 
@@ -12,9 +21,12 @@ impl Plugin for TransitionPlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<AppState>()
             .add_event::<SceneTransitionEvent>()
-            .add_systems(PreStartup, setup_transition_asset_server)
+            .init_resource::<TransitionQueue>()
             .add_systems(PreUpdate, update_scene_transition)
-            .add_systems(PostUpdate, handle_scene_transition_event);
+            .add_systems(
+                PostUpdate,
+                (handle_scene_transition_event, start_queued_transition).chain(),
+            );
     }
 }
 
@@ -22,136 +34,345 @@ impl Plugin for TransitionPlugin {
  * - Constants
  */
 
-const TRANSITION_TEXTURE_ATLAS_DESCS: [(&str, (usize, usize)); 3] = [
-    ("ui/transitions/screen_fade.png", (21, 1)),
-    ("ui/transitions/white_screen_fade.png", (21, 1)),
-    ("ui/transitions/screen_wipe.png", (14, 1)),
-];
-
 const DEFAULT_TRANSITION_INTERVAL: f32 = 0.15;
 
+// Duration of each half of the effects that are drawn instead of loaded from an atlas
+const PROCEDURAL_TRANSITION_DURATION: f32 = 0.6;
+
+const CHECKERBOARD_CELL_SIZE: usize = 16;
+
 /************************************************************
  * - Types
  */
 
-#[derive(Debug, Resource)]
-struct TransitionAssetServer {
-    assets: Vec<Handle<TextureAtlas>>,
+#[derive(Debug, Clone, Copy)]
+pub enum TransitionEffect {
+    Fade,
+    WhiteFade,
+    Wipe,
+    // Circle closing on, and opening from, the given position of the viewport
+    Iris(Vec2),
+    Checkerboard,
 }
 
-impl TransitionAssetServer {
-    fn new() -> Self {
-        Self { assets: vec![] }
+impl TransitionEffect {
+    fn asset_id(&self) -> Option<&'static str> {
+        return match self {
+            Self::Fade => Some("transition_fade"),
+            Self::WhiteFade => Some("transition_white_fade"),
+            Self::Wipe => Some("transition_wipe"),
+            Self::Iris(_) | Self::Checkerboard => None,
+        };
+    }
+
+    // Whether the pixel is covered when the screen is `coverage` of the way to being covered
+    fn covers(&self, pixel: Vec2, coverage: f32, reverse: bool) -> bool {
+        let (width, height) = (
+            window::VIEWPORT_RESOLUTION.0 as f32,
+            window::VIEWPORT_RESOLUTION.1 as f32,
+        );
+
+        let threshold = match self {
+            Self::Iris(focus) => {
+                let radius = Vec2::new(width, height).length();
+                1.0 - (pixel.distance(*focus) / radius).min(1.0)
+            }
+            Self::Checkerboard => {
+                let size = CHECKERBOARD_CELL_SIZE as f32;
+                let (x, y) = ((pixel.x / size) as usize, (pixel.y / size) as usize);
+                let (columns, rows) = ((width / size).ceil(), (height / size).ceil());
+
+                // Every other cell is covered first, each half sweeping across the screen
+                let sweep = (x + y) as f32 / (columns + rows);
+                (((x + y) % 2) as f32 + sweep) / 2.0
+            }
+            _ => 0.0,
+        };
+
+        // Reversed effects cover the screen in the opposite order
+        let threshold = if reverse { 1.0 - threshold } else { threshold };
+
+        return coverage >= 1.0 || coverage > threshold;
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionMode {
+    // Covers the screen, switches the state and uncovers it
+    Full,
+    // Covers the screen and switches the state, the screen stays covered until the next transition
+    Out,
+    // Switches the state right away and uncovers the screen
+    In,
+}
+
+// Applied to the game right before the state is switched
 #[derive(Debug, Clone, Copy)]
-pub enum TransitionEffect {
-    Fade = 0,
-    WhiteFade,
-    Wipe,
+pub enum TransitionPayload {
+    Level(usize),
+}
+
+pub struct TransitionCallback(Box<dyn FnOnce(&mut World) + Send + Sync>);
+
+impl fmt::Debug for TransitionCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "TransitionCallback");
+    }
 }
 
 #[derive(Debug, Event)]
 pub struct SceneTransitionEvent {
     effect: TransitionEffect,
     next: AppState,
+    mode: TransitionMode,
+    reverse: bool,
+    payload: Option<TransitionPayload>,
+    callback: Option<TransitionCallback>,
 }
 
 impl SceneTransitionEvent {
     pub fn new(effect: TransitionEffect, next: AppState) -> Self {
-        Self { effect, next }
+        Self {
+            effect,
+            next,
+            mode: TransitionMode::Full,
+            reverse: false,
+            payload: None,
+            callback: None,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: TransitionMode) -> Self {
+        self.mode = mode;
+        return self;
+    }
+
+    pub fn reversed(mut self) -> Self {
+        self.reverse = true;
+        return self;
+    }
+
+    pub fn with_payload(mut self, payload: TransitionPayload) -> Self {
+        self.payload = Some(payload);
+        return self;
+    }
+
+    // Runs with exclusive access to the World once the screen is covered
+    pub fn with_callback(
+        mut self,
+        callback: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) -> Self {
+        self.callback = Some(TransitionCallback(Box::new(callback)));
+        return self;
     }
 }
 
+// Transitions requested while another one is playing, started in order
+#[derive(Debug, Default, Resource)]
+struct TransitionQueue(VecDeque<SceneTransitionEvent>);
+
 #[derive(Debug, Component)]
 pub struct SceneTransition {
-    half: Timer,
+    effect: TransitionEffect,
+    mode: TransitionMode,
+    reverse: bool,
     next: AppState,
+    payload: Option<TransitionPayload>,
+    callback: Option<TransitionCallback>,
+    // Duration of covering, or uncovering, the screen
+    half: f32,
+    timer: Timer,
+    switched: bool,
+    // Drawn into every frame by the procedural effects
+    image: Option<Handle<Image>>,
 }
 
 impl SceneTransition {
-    fn new(duration: f32, next: AppState) -> Self {
+    fn new(event: SceneTransitionEvent, half: f32) -> Self {
+        let duration = match event.mode {
+            TransitionMode::Full => half * 2.0,
+            TransitionMode::Out | TransitionMode::In => half,
+        };
+
         Self {
-            half: Timer::from_seconds(duration, TimerMode::Once),
-            next,
+            effect: event.effect,
+            mode: event.mode,
+            reverse: event.reverse,
+            next: event.next,
+            payload: event.payload,
+            callback: event.callback,
+            half,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            switched: false,
+            image: None,
         }
     }
+
+    // Time since the start of a full transition, `In` transitions start halfway through
+    fn elapsed(&self) -> f32 {
+        return match self.mode {
+            TransitionMode::Full | TransitionMode::Out => self.timer.elapsed_secs(),
+            TransitionMode::In => self.timer.elapsed_secs() + self.half,
+        };
+    }
+
+    // How much of the screen is covered, from 0.0 to 1.0
+    fn coverage(&self) -> f32 {
+        let elapsed = self.elapsed();
+
+        if elapsed <= self.half {
+            return elapsed / self.half;
+        }
+
+        return (1.0 - ((elapsed - self.half) / self.half)).max(0.0);
+    }
+
+    // Atlas frame for the current point of the transition, the middle frame covers the screen
+    fn frame(&self, frame_count: usize) -> usize {
+        let frame = ((self.elapsed() / (self.half * 2.0)) * frame_count as f32) as usize;
+        let frame = frame.min(frame_count - 1);
+
+        if self.reverse {
+            return frame_count - 1 - frame;
+        }
+
+        return frame;
+    }
+
+    // Time at which the state is switched
+    fn midpoint(&self) -> f32 {
+        return match self.mode {
+            TransitionMode::Full | TransitionMode::Out => self.half,
+            TransitionMode::In => 0.0,
+        };
+    }
 }
 
+// Last frame of an `Out` transition, kept until the next transition starts
+#[derive(Debug, Component)]
+struct TransitionCover;
+
 /************************************************************
  * - System Functions
  */
 
-fn setup_transition_asset_server(
-    mut commands: Commands,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    asset_server: Res<AssetServer>,
+// Events are drained instead of read, so their callbacks can be moved into the queue
+fn handle_scene_transition_event(
+    mut events: ResMut<Events<SceneTransitionEvent>>,
+    mut queue: ResMut<TransitionQueue>,
 ) {
-    let mut tas = TransitionAssetServer::new();
-
-    for (path, atlas) in TRANSITION_TEXTURE_ATLAS_DESCS {
-        let texture_atlas = TextureAtlas::from_grid(
-            asset_server.load(path),
-            Vec2::new(
-                global::window::VIEWPORT_RESOLUTION.0 as f32,
-                global::window::VIEWPORT_RESOLUTION.1 as f32,
-            ),
-            atlas.0,
-            atlas.1,
-            None,
-            None,
-        );
-
-        tas.assets.push(texture_atlases.add(texture_atlas));
+    for event in events.drain() {
+        queue.0.push_back(event);
     }
-
-    commands.insert_resource(tas);
 }
 
-fn handle_scene_transition_event(
+fn start_queued_transition(
     mut commands: Commands,
-    mut event_reader: EventReader<SceneTransitionEvent>,
+    mut queue: ResMut<TransitionQueue>,
     mut app_state: ResMut<NextState<AppState>>,
+    mut images: ResMut<Assets<Image>>,
+    mut error_writer: EventWriter<GameErrorEvent>,
     query: Query<&SceneTransition>,
-    tas: Res<TransitionAssetServer>,
+    covers: Query<Entity, With<TransitionCover>>,
+    gas: Res<GameAssetServer>,
 ) {
-    if event_reader.is_empty() {
-        return;
-    }
-
     if !query.is_empty() {
         return;
     }
 
-    let event = event_reader.iter().next().unwrap();
+    let event = match queue.0.pop_front() {
+        Some(event) => event,
+        None => return,
+    };
+
+    for entity in &covers {
+        commands.entity(entity).despawn_recursive();
+    }
 
-    app_state.set(AppState::Transition);
+    let mode = event.mode;
 
-    spawn_new_scene_transition(&mut commands, &tas, event);
+    if let Err(e) = spawn_new_scene_transition(&mut commands, &mut images, &gas, event) {
+        error_writer.send(GameErrorEvent::new(e));
+        return;
+    }
 
-    event_reader.clear();
+    if mode != TransitionMode::In {
+        app_state.set(AppState::Transition);
+    }
 }
 
 fn update_scene_transition(
-    mut query: Query<&mut SceneTransition>,
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut SceneTransition,
+        Option<&mut TextureAtlasSprite>,
+    )>,
     mut app_state: ResMut<NextState<AppState>>,
+    mut images: ResMut<Assets<Image>>,
+    gas: Res<GameAssetServer>,
     time: Res<Time>,
 ) {
     // Get SceneTransition
-    let mut st = match query.get_single_mut() {
+    let (entity, mut st, sprite) = match query.get_single_mut() {
         Ok(st) => st,
         Err(QuerySingleError::MultipleEntities(_)) => {
-            panic!("Multiple SceneTransitions are deteced.")
+            error!("Multiple SceneTransitions are deteced.");
+            return;
         }
         Err(QuerySingleError::NoEntities(_)) => return,
     };
 
-    st.half.tick(time.delta());
+    st.timer.tick(time.delta());
+
+    if !st.switched && st.timer.elapsed_secs() >= st.midpoint() {
+        st.switched = true;
+
+        if let Some(TransitionPayload::Level(index)) = st.payload {
+            commands.add(move |world: &mut World| {
+                if let Some(mut level) = world.get_resource_mut::<Level>() {
+                    if index < level.maximum {
+                        level.current = index;
+                    }
+                }
+            });
+        }
+
+        if let Some(callback) = st.callback.take() {
+            commands.add(callback.0);
+        }
 
-    if st.half.just_finished() {
         app_state.set(st.next);
     }
+
+    // Draw the current point of the effect
+    match (st.effect.asset_id(), sprite, &st.image) {
+        (Some(id), Some(mut sprite), _) => {
+            if let Ok(asset) = gas.atlas(id) {
+                sprite.index = st.frame(asset.desc.frame_count());
+            }
+        }
+        (None, _, Some(handle)) => {
+            if let Some(image) = images.get_mut(handle) {
+                draw_procedural_transition(image, st.effect, st.coverage(), st.reverse);
+            }
+        }
+        _ => {}
+    }
+
+    if !st.timer.finished() {
+        return;
+    }
+
+    if st.mode == TransitionMode::Out {
+        commands
+            .entity(entity)
+            .remove::<SceneTransition>()
+            .insert(TransitionCover);
+        return;
+    }
+
+    commands.entity(entity).despawn_recursive();
 }
 
 /************************************************************
@@ -160,37 +381,98 @@ fn update_scene_transition(
 
 fn spawn_new_scene_transition(
     commands: &mut Commands,
-    tas: &TransitionAssetServer,
-    event: &SceneTransitionEvent,
-) -> Entity {
-    let (_, texture_atlas_size) = TRANSITION_TEXTURE_ATLAS_DESCS[event.effect as usize];
+    images: &mut Assets<Image>,
+    gas: &GameAssetServer,
+    event: SceneTransitionEvent,
+) -> GameResult<Entity> {
+    let transform =
+        Transform::from_xyz(0.0, 0.0, RENDER_LAYER[RenderLayer::Overlay as usize] as f32);
+
+    if let Some(id) = event.effect.asset_id() {
+        let asset = gas.atlas(id)?;
+        let half = (asset.desc.frame_count() as f32 / 2.0) * DEFAULT_TRANSITION_INTERVAL;
+
+        let transition = SceneTransition::new(event, half);
+        let index = transition.frame(asset.desc.frame_count());
+
+        return Ok(commands
+            .spawn((
+                SpriteSheetBundle {
+                    transform,
+                    texture_atlas: asset.handle.clone(),
+                    sprite: TextureAtlasSprite {
+                        index,
+                        anchor: Anchor::BottomLeft,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                transition,
+                Name::new("Scene Transition Effect"),
+            ))
+            .id());
+    }
+
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: window::VIEWPORT_RESOLUTION.0 as u32,
+            height: window::VIEWPORT_RESOLUTION.1 as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+    );
+
+    let transition = SceneTransition::new(event, PROCEDURAL_TRANSITION_DURATION);
+    draw_procedural_transition(
+        &mut image,
+        transition.effect,
+        transition.coverage(),
+        transition.reverse,
+    );
 
-    let frame_count = texture_atlas_size.0 * texture_atlas_size.1;
-    let transition_duration = (frame_count as f32 / 2.0) * DEFAULT_TRANSITION_INTERVAL;
+    let handle = images.add(image);
 
-    return commands
+    return Ok(commands
         .spawn((
-            SpriteSheetBundle {
-                transform: Transform::from_xyz(
-                    0.0,
-                    0.0,
-                    RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
-                ),
-                texture_atlas: tas.assets[event.effect as usize].clone(),
-                sprite: TextureAtlasSprite {
-                    index: 0,
+            SpriteBundle {
+                transform,
+                texture: handle.clone(),
+                sprite: Sprite {
                     anchor: Anchor::BottomLeft,
                     ..Default::default()
                 },
                 ..Default::default()
             },
-            Animate::new(
-                frame_count,
-                DEFAULT_TRANSITION_INTERVAL,
-                AnimationMode::Delete,
-            ),
-            SceneTransition::new(transition_duration, event.next),
+            SceneTransition {
+                image: Some(handle),
+                ..transition
+            },
             Name::new("Scene Transition Effect"),
         ))
-        .id();
+        .id());
+}
+
+fn draw_procedural_transition(
+    image: &mut Image,
+    effect: TransitionEffect,
+    coverage: f32,
+    reverse: bool,
+) {
+    let (width, height) = window::VIEWPORT_RESOLUTION;
+
+    for row in 0..height {
+        for column in 0..width {
+            // Image rows start at the top, the viewport at the bottom
+            let pixel = Vec2::new(column as f32 + 0.5, (height - 1 - row) as f32 + 0.5);
+            let alpha = if effect.covers(pixel, coverage, reverse) {
+                255
+            } else {
+                0
+            };
+
+            image.data[((row * width) + column) * 4 + 3] = alpha;
+        }
+    }
 }
@@ -0,0 +1,150 @@
+use crate::settings::Settings;
+use bevy::{prelude::*, transform::TransformSystem};
+
+pub struct TweenPlugin;
+
+impl Plugin for TweenPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_set(
+            PostUpdate,
+            TweenSystem.before(TransformSystem::TransformPropagate),
+        )
+        .add_systems(PreUpdate, skip_tweens)
+        .add_systems(PostUpdate, update_tweens.in_set(TweenSystem));
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+pub const TWEEN_UFO_DURATION: f32 = 0.08;
+
+pub const TWEEN_OBJECT_DURATION: f32 = 0.15;
+
+/************************************************************
+ * - Types
+ */
+
+// Systems in PostUpdate that move tweened entities have to run before this set
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct TweenSystem;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum Ease {
+    Linear,
+    QuadOut,
+    CubicInOut,
+}
+
+impl Ease {
+    fn apply(&self, t: f32) -> f32 {
+        return match self {
+            Self::Linear => t,
+            Self::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Self::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        };
+    }
+}
+
+// Animates an entity towards the translation last written to its Transform, so systems
+// keep placing entities directly and the tween only changes what is drawn in between.
+// Only x and y are animated, draw order is left to `RenderOrder`.
+#[derive(Debug, Component)]
+pub struct Tween {
+    timer: Timer,
+    ease: Ease,
+    start: Vec2,
+    target: Vec2,
+    // Position drawn during the last frame, None until the first update
+    current: Option<Vec2>,
+}
+
+impl Tween {
+    pub fn new(duration: f32, ease: Ease) -> Self {
+        let mut timer = Timer::from_seconds(duration, TimerMode::Once);
+        timer.tick(timer.duration());
+
+        Self {
+            timer,
+            ease,
+            start: Vec2::ZERO,
+            target: Vec2::ZERO,
+            current: None,
+        }
+    }
+
+    // Jumps straight to the target on the next update
+    pub fn finish(&mut self) {
+        let duration = self.timer.duration();
+        self.timer.tick(duration);
+    }
+
+    pub fn finished(&self) -> bool {
+        return self.timer.finished();
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+// Actions skip whatever is still moving, so the board always shows the state being acted on
+fn skip_tweens(mut query: Query<&mut Tween>, settings: Res<Settings>, keys: Res<Input<KeyCode>>) {
+    let input = &settings.input;
+
+    if !keys.any_just_pressed([input.lift, input.cancel, input.restart]) {
+        return;
+    }
+
+    for mut tween in &mut query {
+        if !tween.finished() {
+            tween.finish();
+        }
+    }
+}
+
+fn update_tweens(
+    mut query: Query<(&mut Tween, &mut Transform)>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    for (mut tween, mut transform) in &mut query {
+        let position = transform.translation.truncate();
+
+        let current = match tween.current {
+            Some(current) => current,
+            None => {
+                tween.start = position;
+                tween.target = position;
+                tween.current = Some(position);
+                continue;
+            }
+        };
+
+        // Translation was written since the last frame, continue from what is on screen
+        if position != current && position != tween.target {
+            tween.start = current;
+            tween.target = position;
+            tween.timer.reset();
+        }
+
+        tween
+            .timer
+            .tick(time.delta().mul_f32(settings.gameplay.animation_speed));
+
+        let t = tween.ease.apply(tween.timer.percent());
+        let position = tween.start.lerp(tween.target, t);
+
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        tween.current = Some(position);
+    }
+}
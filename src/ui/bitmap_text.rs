@@ -0,0 +1,205 @@
+use crate::asset::{BitmapFontAsset, GameAssetServer};
+use crate::scene::error::GameErrorEvent;
+use bevy::{prelude::*, sprite::Anchor};
+use serde::{Deserialize, Serialize};
+
+pub struct BitmapTextPlugin;
+
+impl Plugin for BitmapTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, layout_bitmap_text.in_set(BitmapTextSystem));
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+pub const BITMAP_TEXT_DEFAULT_FONT: &str = "font";
+
+/************************************************************
+ * - Types
+ */
+
+// Systems changing `BitmapText` in PostUpdate run before this to be drawn the same frame
+#[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
+pub struct BitmapTextSystem;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+// Lays out glyphs as children, the first line hangs below the entity's position
+#[derive(Debug, Component)]
+pub struct BitmapText {
+    pub text: String,
+    pub font: String,
+    pub align: TextAlign,
+    // Wraps lines at spaces to fit the width in pixels
+    pub width: Option<u32>,
+    pub color: Color,
+}
+
+impl BitmapText {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            font: BITMAP_TEXT_DEFAULT_FONT.to_string(),
+            align: TextAlign::Left,
+            width: None,
+            color: Color::WHITE,
+        }
+    }
+
+    pub fn with_font(mut self, font: &str) -> Self {
+        self.font = font.to_string();
+        return self;
+    }
+
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        return self;
+    }
+
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        return self;
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        return self;
+    }
+}
+
+#[derive(Debug, Component)]
+struct BitmapGlyph;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn layout_bitmap_text(
+    mut commands: Commands,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    query: Query<(Entity, &BitmapText), Changed<BitmapText>>,
+    gas: Res<GameAssetServer>,
+) {
+    for (entity, text) in &query {
+        let font = match gas.font(&text.font) {
+            Ok(font) => font,
+            Err(e) => {
+                error_writer.send(GameErrorEvent::new(e));
+                continue;
+            }
+        };
+
+        commands.entity(entity).despawn_descendants();
+
+        let mut glyphs = vec![];
+
+        for (index, position) in layout(&text.text, font, text.align, text.width) {
+            glyphs.push(
+                commands
+                    .spawn((
+                        SpriteSheetBundle {
+                            transform: Transform::from_xyz(
+                                position.x as f32,
+                                position.y as f32,
+                                0.0,
+                            ),
+                            texture_atlas: font.handle.clone(),
+                            sprite: TextureAtlasSprite {
+                                index,
+                                color: text.color,
+                                anchor: Anchor::BottomLeft,
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        BitmapGlyph,
+                    ))
+                    .id(),
+            );
+        }
+
+        commands.entity(entity).push_children(&glyphs);
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+// Atlas index and bottom left pixel of every visible glyph
+fn layout(
+    text: &str,
+    font: &BitmapFontAsset,
+    align: TextAlign,
+    width: Option<u32>,
+) -> Vec<(usize, IVec2)> {
+    let advance = font.advance() as i32;
+    let mut glyphs = vec![];
+
+    for (row, line) in wrap(text, font, width).iter().enumerate() {
+        let count = line.chars().count() as i32;
+        let line_width = (count * advance - font.desc.spacing as i32).max(0);
+
+        let x = match align {
+            TextAlign::Left => 0,
+            TextAlign::Center => -line_width / 2,
+            TextAlign::Right => -line_width,
+        };
+        let y = -((row as i32 + 1) * font.desc.line_height as i32);
+
+        for (i, c) in line.chars().enumerate() {
+            if let Some(index) = font.glyph(c) {
+                glyphs.push((index, IVec2::new(x + i as i32 * advance, y)));
+            }
+        }
+    }
+
+    return glyphs;
+}
+
+fn wrap(text: &str, font: &BitmapFontAsset, width: Option<u32>) -> Vec<String> {
+    let width = match width {
+        Some(width) => width,
+        None => return text.lines().map(|line| line.to_string()).collect(),
+    };
+
+    // Glyphs that fit on a line, the last one doesn't need the spacing
+    let columns = ((width + font.desc.spacing) / font.advance()).max(1) as usize;
+    let mut lines = vec![];
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split(' ') {
+            let mut word = word.to_string();
+
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > columns {
+                lines.push(std::mem::take(&mut line));
+            } else if !line.is_empty() {
+                line.push(' ');
+            }
+
+            // Words longer than a line are broken up
+            while word.chars().count() > columns {
+                let rest = word.split_off(word.char_indices().nth(columns).unwrap().0);
+                lines.push(word);
+                word = rest;
+            }
+
+            line += &word;
+        }
+
+        lines.push(line);
+    }
+
+    return lines;
+}
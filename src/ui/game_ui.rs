@@ -1,30 +1,35 @@
+use super::bitmap_text::{BitmapText, BitmapTextSystem, TextAlign, BITMAP_TEXT_DEFAULT_FONT};
 use crate::{
+    asset::GameAssetServer,
+    error::{self, GameResult},
+    generator::{daily::DailyChallenge, RandomLevel},
     global::window,
     render::{RenderLayer, RENDER_LAYER},
-    scene::level::{Level, Score, TurnCounter},
+    scene::{
+        editor::EditorLevel,
+        error::GameErrorEvent,
+        level::{self, Level, Score, TurnCounter},
+    },
     state::AppState,
 };
-use bevy::{prelude::*, sprite::Anchor};
+use bevy::{ecs::system::SystemParam, prelude::*, sprite::Anchor};
+use serde::{Deserialize, Serialize};
 
 pub struct GameUIPlugin;
 
 impl Plugin for GameUIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, setup_ui_number_texture_atlas)
-            .add_systems(OnEnter(AppState::Game), load_game_ui)
-            .add_systems(OnExit(AppState::Game), unload_game_ui)
-            .add_systems(
-                PostUpdate,
-                (
-                    setup_ui_numbers::<UINumberScore, Score>,
-                    setup_ui_numbers::<UINumberLevel, Level>,
-                    setup_ui_numbers::<UINumberTurn, TurnCounter>,
-                    update_ui_numbers::<UINumberScore, Score>,
-                    update_ui_numbers::<UINumberLevel, Level>,
-                    update_ui_numbers::<UINumberTurn, TurnCounter>,
-                )
-                    .run_if(in_state(AppState::Game)),
-            );
+        app.add_systems(
+            OnEnter(AppState::Game),
+            load_game_ui.after(level::load_level),
+        )
+        .add_systems(OnExit(AppState::Game), unload_game_ui)
+        .add_systems(
+            PostUpdate,
+            update_ui_numbers
+                .before(BitmapTextSystem)
+                .run_if(in_state(AppState::Game)),
+        );
     }
 }
 
@@ -40,226 +45,192 @@ pub trait GameUINumberValue {
  * - Constants
  */
 
-const UI_IMAGES_PATHS: [(&str, (usize, usize)); 4] = [
-    ("ui/game/panel.png", (0, 0)),
-    ("ui/game/score.png", (33, 7)),
-    ("ui/game/day.png", (28, 7)),
-    ("ui/game/turn.png", (32, 7)),
-];
-
-const UI_TEXT_IMAGE_OFFSET: usize = 13;
-
-const UI_NUMBER_TEXTURE_ATLAS_PATH: &str = "ui/game/numbers.png";
-
-const UI_NUMBER_TEXTURE_ATLAS_TILE: (usize, usize) = (6, 7);
-
-const UI_NUMBER_TEXTURE_ATLAS_SIZE: (usize, usize) = (10, 1);
-
-const UI_TEXT_NUMBER_OFFSET: usize = 4;
+const HUD_LAYOUT_PATH: &str = "assets/hud-layout.ron";
 
 /************************************************************
  * - Types
  */
 
-#[derive(Debug, Resource)]
-struct UINumberTextureAtlas(Handle<TextureAtlas>);
-
-#[derive(Debug, Component)]
-struct GameUI;
+// Resources a number widget can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HudValue {
+    Score,
+    Level,
+    Turn,
+    // Seed of the daily challenge or random level, empty in the campaign
+    Seed,
+}
 
-#[derive(Debug, Component)]
-struct UINumberScore;
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum HudWidgetKind {
+    // Texture id from the asset manifest
+    Image(String),
+    Text(String),
+    // Padded with zeros to `digits`, larger values grow wider
+    Number { value: HudValue, digits: usize },
+}
 
-#[derive(Debug, Component)]
-struct UINumberLevel;
+// Corner of the viewport a widget's position is relative to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HudAnchor {
+    #[default]
+    BottomLeft,
+    BottomRight,
+    TopLeft,
+    TopRight,
+}
 
-#[derive(Debug, Component)]
-struct UINumberTurn;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HudMode {
+    Campaign,
+    Daily,
+    Random,
+    TestPlay,
+}
 
-/************************************************************
- * - System Functions
- */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HudWidget {
+    pub kind: HudWidgetKind,
+    // Bottom left of images, top of text since lines hang below it
+    pub position: IVec2,
+    #[serde(default)]
+    pub anchor: HudAnchor,
+    #[serde(default)]
+    pub align: TextAlign,
+    #[serde(default = "default_font")]
+    pub font: String,
+    // Modes the widget is shown in, every mode when empty
+    #[serde(default)]
+    pub modes: Vec<HudMode>,
+}
 
-fn setup_ui_number_texture_atlas(
-    mut commands: Commands,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    asset_server: Res<AssetServer>,
-) {
-    let texture_atlas = TextureAtlas::from_grid(
-        asset_server.load(UI_NUMBER_TEXTURE_ATLAS_PATH),
-        Vec2::new(
-            UI_NUMBER_TEXTURE_ATLAS_TILE.0 as f32,
-            UI_NUMBER_TEXTURE_ATLAS_TILE.1 as f32,
-        ),
-        UI_NUMBER_TEXTURE_ATLAS_SIZE.0,
-        UI_NUMBER_TEXTURE_ATLAS_SIZE.1,
-        None,
-        None,
-    );
+impl HudWidget {
+    // Looks up the texture or font, so a typo is reported before anything is spawned
+    fn validate(&self, gas: &GameAssetServer) -> GameResult<()> {
+        match &self.kind {
+            HudWidgetKind::Image(id) => {
+                gas.texture(id)?;
+            }
+            HudWidgetKind::Text(_) | HudWidgetKind::Number { .. } => {
+                gas.font(&self.font)?;
+            }
+        }
 
-    commands.insert_resource(UINumberTextureAtlas(texture_atlases.add(texture_atlas)));
+        return Ok(());
+    }
 }
 
-fn setup_ui_numbers<T: Component, U: Resource + GameUINumberValue + std::fmt::Debug>(
-    mut c_query: Query<&mut TextureAtlasSprite>,
-    p_query: Query<&Children, Added<T>>,
-    resource: Res<U>,
-) {
-    for children in &p_query {
-        let nums = format!("{:0width$}", resource.value(), width = 3);
+#[derive(Debug, Serialize, Deserialize)]
+struct HudLayout {
+    widgets: Vec<HudWidget>,
+}
 
-        for (i, child) in children.iter().enumerate() {
-            let mut sprite = c_query.get_mut(*child).unwrap();
+impl HudLayout {
+    fn load() -> GameResult<Self> {
+        return error::read_ron(HUD_LAYOUT_PATH);
+    }
+}
 
-            let num = (nums.as_bytes()[i] as char).to_digit(10).unwrap();
+#[derive(Debug, Component)]
+struct GameUI;
 
-            sprite.index = num as usize;
-        }
-    }
+// Bound value and the digits it's padded to
+#[derive(Debug, Component)]
+struct UINumber(HudValue, usize);
+
+#[derive(SystemParam)]
+struct HudValues<'w> {
+    score: Res<'w, Score>,
+    level: Res<'w, Level>,
+    turn_counter: Res<'w, TurnCounter>,
+    daily: Option<Res<'w, DailyChallenge>>,
+    random: Option<Res<'w, RandomLevel>>,
 }
 
-fn update_ui_numbers<T: Component, U: Resource + GameUINumberValue + std::fmt::Debug>(
-    mut c_query: Query<&mut TextureAtlasSprite>,
-    p_query: Query<&Children, With<T>>,
-    resource: Res<U>,
-) {
-    if !resource.is_changed() {
-        return;
+impl<'w> HudValues<'w> {
+    // Current value and whether it changed this frame, None when the resource is missing
+    fn get(&self, value: HudValue) -> Option<(usize, bool)> {
+        return match value {
+            HudValue::Score => Some((self.score.value(), self.score.is_changed())),
+            HudValue::Level => Some((self.level.value(), self.level.is_changed())),
+            HudValue::Turn => Some((self.turn_counter.value(), self.turn_counter.is_changed())),
+            HudValue::Seed => match (&self.random, &self.daily) {
+                (Some(random), _) => Some((random.value(), random.is_changed())),
+                (_, Some(daily)) => Some((daily.value(), daily.is_changed())),
+                _ => None,
+            },
+        };
     }
+}
 
-    for children in &p_query {
-        let nums = format!("{:0width$}", resource.value(), width = 3);
-
-        for (i, child) in children.iter().enumerate() {
-            let mut sprite = c_query.get_mut(*child).unwrap();
+/************************************************************
+ * - System Functions
+ */
 
-            let num = (nums.as_bytes()[i] as char).to_digit(10).unwrap();
+fn update_ui_numbers(mut query: Query<(&mut BitmapText, Ref<UINumber>)>, values: HudValues) {
+    for (mut text, number) in &mut query {
+        let (value, changed) = match values.get(number.0) {
+            Some(value) => value,
+            None => continue,
+        };
 
-            sprite.index = num as usize;
+        if !changed && !number.is_added() {
+            continue;
         }
+
+        text.text = format!("{:0width$}", value, width = number.1);
     }
 }
 
 fn load_game_ui(
     mut commands: Commands,
-    number_texture_atlas: Res<UINumberTextureAtlas>,
-    asset_server: Res<AssetServer>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
+    level: Res<Level>,
+    editor: Option<Res<EditorLevel>>,
+    random: Option<Res<RandomLevel>>,
+    daily: Option<Res<DailyChallenge>>,
 ) {
+    let layout = match HudLayout::load() {
+        Ok(layout) => layout,
+        Err(e) => {
+            error_writer.send(GameErrorEvent::new(e));
+            return;
+        }
+    };
+
+    // Same precedence as the level that was loaded
+    let mode = match (editor, random, daily) {
+        (Some(editor), _, _) if editor.playing => HudMode::TestPlay,
+        (_, Some(_), _) => HudMode::Random,
+        (_, _, Some(_)) => HudMode::Daily,
+        _ => HudMode::Campaign,
+    };
+
+    // Widgets of the level are drawn on top of the layout
+    let widgets: Vec<&HudWidget> = layout.widgets.iter().chain(level.hud.iter()).collect();
+
+    if let Some(e) = widgets
+        .iter()
+        .find_map(|widget| widget.validate(&gas).err())
+    {
+        error_writer.send(GameErrorEvent::new(e));
+        return;
+    }
+
     let mut children = vec![];
 
-    let panelw = window::VIEWPORT_RESOLUTION.0;
-    let offset = panelw / 3;
-    let ui_number_w = (offset - (3 * UI_NUMBER_TEXTURE_ATLAS_TILE.0)) / 2;
-
-    let mut index = 0;
-
-    // Panel
-    let ui_desc = UI_IMAGES_PATHS[index];
-    children.push(spawn_ui_node(
-        Vec2::new(0.0, 0.0),
-        index,
-        ui_desc.0,
-        "Panel",
-        &mut commands,
-        &asset_server,
-    ));
-    index += 1;
-
-    // Score Text
-    let ui_desc = UI_IMAGES_PATHS[index];
-    children.push(spawn_ui_node(
-        Vec2::new(
-            ((offset * (index - 1)) + ((offset - (ui_desc.1).0) / 2)) as f32,
-            UI_TEXT_IMAGE_OFFSET as f32,
-        ),
-        index,
-        ui_desc.0,
-        "Score Text",
-        &mut commands,
-        &asset_server,
-    ));
-
-    let number_entity = commands
-        .spawn((
-            SpatialBundle::from_transform(Transform::from_xyz(
-                ((offset * (index - 1)) + ui_number_w) as f32,
-                UI_TEXT_NUMBER_OFFSET as f32,
-                (RENDER_LAYER[RenderLayer::UI as usize] + index as u32) as f32,
-            )),
-            UINumberScore,
-            Name::new("UI Score Numbers"),
-        ))
-        .id();
-    children.push(number_entity);
-
-    let numbers = spawn_ui_number_node(number_texture_atlas.0.clone(), &mut commands);
-    commands.entity(number_entity).push_children(&numbers);
-
-    index += 1;
-
-    // Level Text
-    let ui_desc = UI_IMAGES_PATHS[index];
-    children.push(spawn_ui_node(
-        Vec2::new(
-            ((offset * (index - 1)) + ((offset - (ui_desc.1).0) / 2)) as f32,
-            UI_TEXT_IMAGE_OFFSET as f32,
-        ),
-        index,
-        ui_desc.0,
-        "Level Text",
-        &mut commands,
-        &asset_server,
-    ));
-
-    let number_entity = commands
-        .spawn((
-            SpatialBundle::from_transform(Transform::from_xyz(
-                ((offset * (index - 1)) + ui_number_w) as f32,
-                UI_TEXT_NUMBER_OFFSET as f32,
-                (RENDER_LAYER[RenderLayer::UI as usize] + index as u32) as f32,
-            )),
-            UINumberLevel,
-            Name::new("UI Level Numbers"),
-        ))
-        .id();
-    children.push(number_entity);
-
-    let numbers = spawn_ui_number_node(number_texture_atlas.0.clone(), &mut commands);
-    commands.entity(number_entity).push_children(&numbers);
-
-    index += 1;
-
-    // Turn Text
-    let ui_desc = UI_IMAGES_PATHS[index];
-    children.push(spawn_ui_node(
-        Vec2::new(
-            ((offset * (index - 1)) + ((offset - (ui_desc.1).0) / 2)) as f32,
-            UI_TEXT_IMAGE_OFFSET as f32,
-        ),
-        index,
-        ui_desc.0,
-        "Turn Text",
-        &mut commands,
-        &asset_server,
-    ));
-
-    let number_entity = commands
-        .spawn((
-            SpatialBundle::from_transform(Transform::from_xyz(
-                ((offset * (index - 1)) + ui_number_w) as f32,
-                UI_TEXT_NUMBER_OFFSET as f32,
-                (RENDER_LAYER[RenderLayer::UI as usize] + index as u32) as f32,
-            )),
-            UINumberTurn,
-            Name::new("UI Turn Numbers"),
-        ))
-        .id();
-    children.push(number_entity);
+    for (order, widget) in widgets.into_iter().enumerate() {
+        if !widget.modes.is_empty() && !widget.modes.contains(&mode) {
+            continue;
+        }
 
-    let numbers = spawn_ui_number_node(number_texture_atlas.0.clone(), &mut commands);
-    commands.entity(number_entity).push_children(&numbers);
+        match spawn_hud_widget(widget, order, &gas, &mut commands) {
+            Ok(child) => children.push(child),
+            Err(e) => error_writer.send(GameErrorEvent::new(e)),
+        }
+    }
 
-    // Game UI
     commands
         .spawn((
             SpatialBundle::from_transform(Transform::from_xyz(0.0, 0.0, 0.0)),
@@ -279,58 +250,68 @@ fn unload_game_ui(mut commands: Commands, query: Query<Entity, With<GameUI>>) {
  * - Helper Functions
  */
 
-fn spawn_ui_number_node(handle: Handle<TextureAtlas>, commands: &mut Commands) -> Vec<Entity> {
-    let mut ids = vec![];
-    for i in 0..3 {
-        ids.push(
-            commands
-                .spawn((
-                    SpriteSheetBundle {
-                        transform: Transform::from_xyz(
-                            (i * UI_NUMBER_TEXTURE_ATLAS_TILE.0) as f32,
-                            0.0,
-                            0.0,
-                        ),
-                        texture_atlas: handle.clone(),
-                        sprite: TextureAtlasSprite {
-                            index: 0,
-                            anchor: Anchor::BottomLeft,
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    },
-                    Name::new(format!("UI Number #{}", i)),
-                ))
-                .id(),
-        );
-    }
-    return ids;
+fn default_font() -> String {
+    return BITMAP_TEXT_DEFAULT_FONT.to_string();
 }
 
-fn spawn_ui_node(
-    position: Vec2,
+fn spawn_hud_widget(
+    widget: &HudWidget,
     order: usize,
-    path: &str,
-    name: &str,
+    gas: &GameAssetServer,
     commands: &mut Commands,
-    asset_server: &AssetServer,
-) -> Entity {
-    commands
-        .spawn((
-            SpriteBundle {
-                transform: Transform::from_xyz(
-                    position.x,
-                    position.y,
-                    (RENDER_LAYER[RenderLayer::UI as usize] + order as u32) as f32,
-                ),
-                texture: asset_server.load(path),
-                sprite: Sprite {
-                    anchor: Anchor::BottomLeft,
+) -> GameResult<Entity> {
+    let (width, height) = (
+        window::VIEWPORT_RESOLUTION.0 as i32,
+        window::VIEWPORT_RESOLUTION.1 as i32,
+    );
+
+    let origin = match widget.anchor {
+        HudAnchor::BottomLeft => IVec2::new(0, 0),
+        HudAnchor::BottomRight => IVec2::new(width, 0),
+        HudAnchor::TopLeft => IVec2::new(0, height),
+        HudAnchor::TopRight => IVec2::new(width, height),
+    };
+    let position = origin + widget.position;
+
+    let transform = Transform::from_xyz(
+        position.x as f32,
+        position.y as f32,
+        (RENDER_LAYER[RenderLayer::UI as usize] + order as u32) as f32,
+    );
+
+    return Ok(match &widget.kind {
+        HudWidgetKind::Image(id) => commands
+            .spawn((
+                SpriteBundle {
+                    transform,
+                    texture: gas.texture(id)?.handle.clone(),
+                    sprite: Sprite {
+                        anchor: Anchor::BottomLeft,
+                        ..Default::default()
+                    },
                     ..Default::default()
                 },
-                ..Default::default()
-            },
-            Name::new(name.to_string()),
-        ))
-        .id()
+                Name::new(format!("UI Image `{}`", id)),
+            ))
+            .id(),
+        HudWidgetKind::Text(text) => commands
+            .spawn((
+                SpatialBundle::from_transform(transform),
+                BitmapText::new(text)
+                    .with_font(&widget.font)
+                    .with_align(widget.align),
+                Name::new("UI Text"),
+            ))
+            .id(),
+        HudWidgetKind::Number { value, digits } => commands
+            .spawn((
+                SpatialBundle::from_transform(transform),
+                BitmapText::new("")
+                    .with_font(&widget.font)
+                    .with_align(widget.align),
+                UINumber(*value, *digits),
+                Name::new(format!("UI Number {:?}", value)),
+            ))
+            .id(),
+    });
 }
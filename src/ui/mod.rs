@@ -1,11 +1,15 @@
 use bevy::prelude::*;
 
+pub mod bitmap_text;
 pub mod game_ui;
+pub mod settings_ui;
 
 pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(game_ui::GameUIPlugin);
+        app.add_plugins(bitmap_text::BitmapTextPlugin)
+            .add_plugins(game_ui::GameUIPlugin)
+            .add_plugins(settings_ui::SettingsUIPlugin);
     }
 }
@@ -0,0 +1,402 @@
+use crate::{
+    game::GameState,
+    global::window,
+    render::{RenderLayer, RENDER_LAYER},
+    settings::{self, Settings},
+    state::AppState,
+    ui::bitmap_text::{BitmapText, TextAlign},
+};
+use bevy::{
+    prelude::*,
+    sprite::Anchor,
+    window::{PresentMode, WindowMode},
+};
+
+pub struct SettingsUIPlugin;
+
+impl Plugin for SettingsUIPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Settings), load_settings_ui)
+            .add_systems(OnExit(GameState::Settings), unload_settings_ui)
+            .add_systems(
+                Update,
+                (control_settings_ui, update_settings_ui)
+                    .chain()
+                    .run_if(in_state(AppState::Game).and_then(in_state(GameState::Settings))),
+            );
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const SETTINGS_ENTRIES: [SettingsEntry; 17] = [
+    SettingsEntry::WindowMode,
+    SettingsEntry::Scale,
+    SettingsEntry::PresentMode,
+    SettingsEntry::MasterVolume,
+    SettingsEntry::MusicVolume,
+    SettingsEntry::SfxVolume,
+    SettingsEntry::AnimationSpeed,
+    SettingsEntry::Binding(Binding::Up),
+    SettingsEntry::Binding(Binding::Down),
+    SettingsEntry::Binding(Binding::Left),
+    SettingsEntry::Binding(Binding::Right),
+    SettingsEntry::Binding(Binding::Lift),
+    SettingsEntry::Binding(Binding::Cancel),
+    SettingsEntry::Binding(Binding::Rotate),
+    SettingsEntry::Binding(Binding::Restart),
+    SettingsEntry::Binding(Binding::Settings),
+    SettingsEntry::Binding(Binding::Fullscreen),
+];
+
+const SETTINGS_UI_MARGIN: (f32, f32) = (6.0, 6.0);
+
+// Entries that fit under the title, the list scrolls to keep the cursor in view
+const SETTINGS_UI_VISIBLE_ENTRIES: usize = 14;
+
+const SETTINGS_UI_BACKGROUND: Color = Color::rgba(0.0, 0.0, 0.0, 0.85);
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Copy)]
+enum Binding {
+    Up,
+    Down,
+    Left,
+    Right,
+    Lift,
+    Cancel,
+    Rotate,
+    Restart,
+    Settings,
+    Fullscreen,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SettingsEntry {
+    WindowMode,
+    Scale,
+    PresentMode,
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    AnimationSpeed,
+    Binding(Binding),
+}
+
+impl SettingsEntry {
+    fn label(&self) -> &'static str {
+        return match self {
+            Self::WindowMode => "Window",
+            Self::Scale => "Scale",
+            Self::PresentMode => "V-Sync",
+            Self::MasterVolume => "Master",
+            Self::MusicVolume => "Music",
+            Self::SfxVolume => "Sfx",
+            Self::AnimationSpeed => "Anim Speed",
+            Self::Binding(binding) => match binding {
+                Binding::Up => "Key Up",
+                Binding::Down => "Key Down",
+                Binding::Left => "Key Left",
+                Binding::Right => "Key Right",
+                Binding::Lift => "Key Lift",
+                Binding::Cancel => "Key Cancel",
+                Binding::Rotate => "Key Rotate",
+                Binding::Restart => "Key Restart",
+                Binding::Settings => "Key Settings",
+                Binding::Fullscreen => "Key Fullscreen",
+            },
+        };
+    }
+
+    fn value(&self, settings: &Settings) -> String {
+        return match self {
+            Self::WindowMode => format!("{:?}", settings.window.mode),
+            Self::Scale => format!("x{}", settings.window.scale),
+            Self::PresentMode => match settings.window.present_mode {
+                PresentMode::Immediate | PresentMode::AutoNoVsync => "Off".to_string(),
+                _ => "On".to_string(),
+            },
+            Self::MasterVolume => format!("{:.0}%", settings.audio.master * 100.0),
+            Self::MusicVolume => format!("{:.0}%", settings.audio.music * 100.0),
+            Self::SfxVolume => format!("{:.0}%", settings.audio.sfx * 100.0),
+            Self::AnimationSpeed => format!("{:.2}", settings.gameplay.animation_speed),
+            Self::Binding(binding) => format!("{:?}", binding_key(binding, settings)),
+        };
+    }
+
+    fn change(&self, direction: i32, settings: &mut Settings) {
+        match self {
+            Self::WindowMode => {
+                const MODES: [WindowMode; 3] = [
+                    WindowMode::Windowed,
+                    WindowMode::BorderlessFullscreen,
+                    WindowMode::Fullscreen,
+                ];
+
+                let current = MODES
+                    .iter()
+                    .position(|mode| *mode == settings.window.mode)
+                    .unwrap_or(0) as i32;
+
+                settings.window.mode =
+                    MODES[(current + direction).rem_euclid(MODES.len() as i32) as usize];
+            }
+            Self::Scale => {
+                settings.window.scale = (settings.window.scale as i32 + direction).clamp(
+                    settings::SCALE_RANGE.0 as i32,
+                    settings::SCALE_RANGE.1 as i32,
+                ) as usize;
+            }
+            Self::PresentMode => {
+                settings.window.present_mode = match settings.window.present_mode {
+                    PresentMode::Immediate | PresentMode::AutoNoVsync => PresentMode::Fifo,
+                    _ => PresentMode::AutoNoVsync,
+                };
+            }
+            Self::MasterVolume => change_volume(&mut settings.audio.master, direction),
+            Self::MusicVolume => change_volume(&mut settings.audio.music, direction),
+            Self::SfxVolume => change_volume(&mut settings.audio.sfx, direction),
+            Self::AnimationSpeed => {
+                settings.gameplay.animation_speed = (settings.gameplay.animation_speed
+                    + (direction as f32 * settings::ANIMATION_SPEED_STEP))
+                    .clamp(
+                        settings::ANIMATION_SPEED_RANGE.0,
+                        settings::ANIMATION_SPEED_RANGE.1,
+                    );
+            }
+            Self::Binding(_) => {}
+        }
+    }
+}
+
+#[derive(Debug, Component)]
+struct SettingsUI {
+    cursor: usize,
+    rebinding: bool,
+}
+
+impl SettingsUI {
+    fn new() -> Self {
+        Self {
+            cursor: 0,
+            rebinding: false,
+        }
+    }
+}
+
+// Labels are aligned to the left of the panel and values to the right
+#[derive(Debug, Component)]
+enum SettingsUIText {
+    Labels,
+    Values,
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn load_settings_ui(mut commands: Commands) {
+    let top = window::VIEWPORT_RESOLUTION.1 as f32 - SETTINGS_UI_MARGIN.1;
+
+    let labels = commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(SETTINGS_UI_MARGIN.0, top, 1.0)),
+            BitmapText::new(""),
+            SettingsUIText::Labels,
+            Name::new("Settings UI Labels"),
+        ))
+        .id();
+
+    let values = commands
+        .spawn((
+            SpatialBundle::from_transform(Transform::from_xyz(
+                window::VIEWPORT_RESOLUTION.0 as f32 - SETTINGS_UI_MARGIN.0,
+                top,
+                1.0,
+            )),
+            BitmapText::new("").with_align(TextAlign::Right),
+            SettingsUIText::Values,
+            Name::new("Settings UI Values"),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(
+                    0.0,
+                    0.0,
+                    RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+                ),
+                sprite: Sprite {
+                    color: SETTINGS_UI_BACKGROUND,
+                    custom_size: Some(Vec2::new(
+                        window::VIEWPORT_RESOLUTION.0 as f32,
+                        window::VIEWPORT_RESOLUTION.1 as f32,
+                    )),
+                    anchor: Anchor::BottomLeft,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            SettingsUI::new(),
+            Name::new("Settings UI"),
+        ))
+        .push_children(&[labels, values]);
+}
+
+fn unload_settings_ui(mut commands: Commands, query: Query<Entity, With<SettingsUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn control_settings_ui(
+    mut query: Query<&mut SettingsUI>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut settings: ResMut<Settings>,
+    keys: Res<Input<KeyCode>>,
+) {
+    let mut ui = match query.get_single_mut() {
+        Ok(ui) => ui,
+        Err(_) => return,
+    };
+
+    // Wait for the next key press while rebinding
+    if ui.rebinding {
+        if let Some(key) = keys.get_just_pressed().next() {
+            if let SettingsEntry::Binding(binding) = SETTINGS_ENTRIES[ui.cursor] {
+                *binding_mut(&binding, &mut settings) = *key;
+            }
+
+            ui.rebinding = false;
+        }
+
+        return;
+    }
+
+    let input = settings.input.clone();
+
+    // Close & Save
+    if keys.just_pressed(input.settings) || keys.just_pressed(input.cancel) {
+        settings.save();
+
+        game_state.set(GameState::PlayerControlled);
+        return;
+    }
+
+    // Navigation
+    if keys.just_pressed(input.up) && ui.cursor > 0 {
+        ui.cursor -= 1;
+    }
+    if keys.just_pressed(input.down) && ui.cursor < SETTINGS_ENTRIES.len() - 1 {
+        ui.cursor += 1;
+    }
+
+    let entry = SETTINGS_ENTRIES[ui.cursor];
+
+    // Change Value
+    if keys.just_pressed(input.left) {
+        entry.change(-1, &mut settings);
+    }
+    if keys.just_pressed(input.right) {
+        entry.change(1, &mut settings);
+    }
+
+    // Rebind
+    if keys.just_pressed(input.lift) {
+        if let SettingsEntry::Binding(_) = entry {
+            ui.rebinding = true;
+        }
+    }
+}
+
+fn update_settings_ui(
+    mut text_query: Query<(&mut BitmapText, &SettingsUIText)>,
+    ui_query: Query<&SettingsUI, Changed<SettingsUI>>,
+    all_query: Query<&SettingsUI>,
+    settings: Res<Settings>,
+) {
+    if ui_query.is_empty() && !settings.is_changed() {
+        return;
+    }
+
+    let ui = match all_query.get_single() {
+        Ok(ui) => ui,
+        Err(_) => return,
+    };
+
+    let first = (ui.cursor + 1).saturating_sub(SETTINGS_UI_VISIBLE_ENTRIES);
+
+    let mut labels = "SETTINGS\n\n".to_string();
+    let mut values = "\n\n".to_string();
+
+    for (i, entry) in SETTINGS_ENTRIES
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(SETTINGS_UI_VISIBLE_ENTRIES)
+    {
+        let cursor = if i == ui.cursor { ">" } else { " " };
+
+        let value = if i == ui.cursor && ui.rebinding {
+            "...".to_string()
+        } else {
+            entry.value(&settings)
+        };
+
+        labels += &format!("{} {}\n", cursor, entry.label());
+        values += &format!("{}\n", value);
+    }
+
+    for (mut text, column) in &mut text_query {
+        text.text = match column {
+            SettingsUIText::Labels => labels.clone(),
+            SettingsUIText::Values => values.clone(),
+        };
+    }
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn binding_key(binding: &Binding, settings: &Settings) -> KeyCode {
+    return match binding {
+        Binding::Up => settings.input.up,
+        Binding::Down => settings.input.down,
+        Binding::Left => settings.input.left,
+        Binding::Right => settings.input.right,
+        Binding::Lift => settings.input.lift,
+        Binding::Cancel => settings.input.cancel,
+        Binding::Rotate => settings.input.rotate,
+        Binding::Restart => settings.input.restart,
+        Binding::Settings => settings.input.settings,
+        Binding::Fullscreen => settings.input.fullscreen,
+    };
+}
+
+fn binding_mut<'a>(binding: &Binding, settings: &'a mut Settings) -> &'a mut KeyCode {
+    return match binding {
+        Binding::Up => &mut settings.input.up,
+        Binding::Down => &mut settings.input.down,
+        Binding::Left => &mut settings.input.left,
+        Binding::Right => &mut settings.input.right,
+        Binding::Lift => &mut settings.input.lift,
+        Binding::Cancel => &mut settings.input.cancel,
+        Binding::Rotate => &mut settings.input.rotate,
+        Binding::Restart => &mut settings.input.restart,
+        Binding::Settings => &mut settings.input.settings,
+        Binding::Fullscreen => &mut settings.input.fullscreen,
+    };
+}
+
+fn change_volume(volume: &mut f32, direction: i32) {
+    *volume = ((*volume + (direction as f32 * settings::VOLUME_STEP)) * 10.0).round() / 10.0;
+    *volume = volume.clamp(0.0, 1.0);
+}
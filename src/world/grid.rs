@@ -4,7 +4,8 @@ pub struct GridPlugin;
 
 impl Plugin for GridPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PreStartup, setup_grid);
+        app.register_type::<Grid>()
+            .add_systems(PreStartup, setup_grid);
     }
 }
 
@@ -24,7 +25,8 @@ const CELL_OFFSET: (u32, u32) = (2, 4);
  * - Types
  */
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct Grid {
     pub cell_offset: (u32, u32),
     pub size: (u32, u32),
@@ -45,10 +47,28 @@ impl Grid {
             cell_offset,
             size,
             offset,
-            grid: vec![1; (size.0 * size.1) as usize],
+            grid: Self::default_mask(size),
         }
     }
 
+    // Every even row starts with a disabled cell to keep the board symmetrical
+    pub fn default_mask(size: (u32, u32)) -> Vec<usize> {
+        let mut mask = vec![1; (size.0 * size.1) as usize];
+
+        for (i, cell) in mask.iter_mut().enumerate() {
+            if (i / size.0 as usize).is_multiple_of(2) && i.is_multiple_of(size.0 as usize) {
+                *cell = 0;
+            }
+        }
+
+        return mask;
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        return (pos.x >= 0 && pos.x < self.size.0 as i32)
+            && (pos.y >= 0 && pos.y < self.size.1 as i32);
+    }
+
     pub fn cell_order(&self, pos: UVec2) -> u32 {
         let index = (pos.y * self.size.0) + pos.x;
 
@@ -56,6 +76,12 @@ impl Grid {
             - (index % self.size.0 + (index / self.size.0 * self.size.0));
     }
 
+    // Middle of the cell in world space
+    pub fn cell_center(&self, pos: UVec2) -> Vec2 {
+        return self.cell_to_world(pos)
+            + Vec2::new(self.cell_size.0 as f32 / 2.0, self.cell_size.1 as f32 / 2.0);
+    }
+
     // TODO: Change `Grid::cell_to_world` to be able to calculate i32 values.
     pub fn cell_to_world(&self, pos: UVec2) -> Vec2 {
         return Vec2::new(
@@ -67,20 +93,18 @@ impl Grid {
     }
 }
 
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new(GRID_SIZE, GRID_OFFET, CELL_SIZE, CELL_OFFSET)
+    }
+}
+
 /************************************************************
  * - System Functions
  */
 
 fn setup_grid(mut commands: Commands) {
-    let mut g = Grid::new(GRID_SIZE, GRID_OFFET, CELL_SIZE, CELL_OFFSET);
-
-    for i in 0..g.grid.len() {
-        if (((i / g.size.0 as usize) % 2) == 0) && (i % g.size.0 as usize == 0) {
-            g.grid[i] = 0;
-        }
-    }
-
-    commands.insert_resource(g);
+    commands.insert_resource(Grid::default());
 }
 
 /************************************************************
@@ -1,18 +1,26 @@
+use crate::error::GameResult;
 use crate::object::asset::ObjectAssetServer;
 use crate::object::{Object, ObjectDesc, ObjectID};
 use crate::world::grid::{Grid, GridPlugin};
+use crate::world::notation::NotationPlugin;
+use crate::world::overlay::OverlayPlugin;
 use crate::world::tile::{TileMap, TilePlugin};
 use bevy::prelude::*;
 
 pub mod grid;
+pub mod notation;
+pub mod overlay;
 pub mod tile;
 
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(GridPlugin)
+        app.register_type::<World>()
+            .add_plugins(GridPlugin)
             .add_plugins(TilePlugin)
+            .add_plugins(NotationPlugin)
+            .add_plugins(OverlayPlugin)
             .add_systems(Startup, setup_world);
     }
 }
@@ -21,7 +29,8 @@ impl Plugin for WorldPlugin {
  * - Types
  */
 
-#[derive(Debug, Resource)]
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
 pub struct World {
     pub size: (u32, u32),
     pub objects: Vec<Option<(Entity, ObjectID)>>,
@@ -36,6 +45,12 @@ impl World {
     }
 }
 
+impl Default for World {
+    fn default() -> Self {
+        Self::new(&Grid::default())
+    }
+}
+
 /************************************************************
  * - System Functions
  */
@@ -77,44 +92,21 @@ pub fn generate_objects(
     texture_atlases: &mut Assets<TextureAtlas>,
     world: &mut World,
     commands: &mut Commands,
-) {
+) -> GameResult<()> {
     world.objects.fill(None);
 
-    let (mut king, mut castle) = (false, false);
-
     for od in objects {
-        if !king {
-            if let ObjectID::King = od.id {
-                king = true;
-            }
-        } else {
-            if let ObjectID::King = od.id {
-                panic!("There can't be more than one King in a level.");
-            }
-        }
-
-        if !castle {
-            if let ObjectID::Castle = od.id {
-                castle = true;
-            }
-        } else {
-            if let ObjectID::Castle = od.id {
-                panic!("There can't be more than one Castle in a level.");
-            }
-        }
-
         Object::new(
             od.id,
             od.position,
+            od.orientation,
             texture_atlases,
             world,
             commands,
             grid,
             oas,
-        );
+        )?;
     }
 
-    if !king | !castle {
-        panic!("Encountered incomplete level, either King or Castle is missing.");
-    }
+    return Ok(());
 }
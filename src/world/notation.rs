@@ -0,0 +1,399 @@
+use super::{grid::Grid, World};
+use crate::{
+    error::{GameError, GameResult},
+    game::ufo::UFO,
+    object::{self, asset::ObjectAssetServer, Object, ObjectDesc, ObjectID},
+    scene::level::LevelDesc,
+    state::AppState,
+};
+use bevy::prelude::*;
+use std::{fmt, str::FromStr};
+
+pub struct NotationPlugin;
+
+impl Plugin for NotationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, log_board.run_if(in_state(AppState::Game)));
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const NOTATION_LOG_KEY: KeyCode = KeyCode::F9;
+
+const NOTATION_EMPTY: char = '.';
+
+const NOTATION_MASKED: char = '-';
+
+// Lines starting with this are skipped by the parser
+const NOTATION_COMMENT: &str = "//";
+
+// Anchor cells use the uppercase letter, the rest of a footprint the lowercase one
+const NOTATION_SYMBOLS: [(ObjectID, char); 13] = [
+    (ObjectID::King, 'K'),
+    (ObjectID::Villager, 'V'),
+    (ObjectID::Cow, 'C'),
+    (ObjectID::Assassin, 'A'),
+    (ObjectID::Castle, 'G'),
+    (ObjectID::Mountain, 'M'),
+    (ObjectID::Field, 'L'),
+    (ObjectID::House, 'H'),
+    (ObjectID::BigHouse, 'B'),
+    (ObjectID::Farm, 'F'),
+    (ObjectID::Tower, 'T'),
+    (ObjectID::Church, 'R'),
+    (ObjectID::Tavern, 'N'),
+];
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardCell {
+    Masked,
+    Empty,
+    Object {
+        id: ObjectID,
+        // Cell the Object is positioned by, `occupied[0]`
+        anchor: bool,
+        carried: bool,
+    },
+}
+
+// Printable snapshot of the board, rows are written top to bottom like they're drawn.
+//
+//  ` K `  cell with an Object    `[K]`  UFO hovering the cell
+//  `(K)`  Object being carried   `{K}`  both
+//  ` . `  empty cell             ` - `  cell outside of the board mask
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    pub size: (u32, u32),
+    pub cells: Vec<BoardCell>,
+    pub ufo: Option<IVec2>,
+}
+
+impl Board {
+    pub fn capture<'a>(
+        world: &World,
+        grid: &Grid,
+        objects: impl IntoIterator<Item = (Entity, &'a Object)>,
+        ufo: Option<&UFO>,
+    ) -> Self {
+        let carried = ufo.and_then(|ufo| ufo.carrying());
+
+        let mut anchors = vec![];
+        for (entity, object) in objects {
+            if let Some(anchor) = object.occupied.first() {
+                anchors.push((entity, *anchor));
+            }
+        }
+
+        let cells = (0..(world.size.0 * world.size.1) as usize)
+            .map(|index| {
+                if grid.grid.get(index).copied().unwrap_or(0) == 0 {
+                    return BoardCell::Masked;
+                }
+
+                let position = IVec2::new(
+                    (index % world.size.0 as usize) as i32,
+                    (index / world.size.0 as usize) as i32,
+                );
+
+                return match world.objects[index] {
+                    Some((entity, id)) => BoardCell::Object {
+                        id,
+                        anchor: anchors.contains(&(entity, position)),
+                        carried: carried == Some(entity),
+                    },
+                    None => BoardCell::Empty,
+                };
+            })
+            .collect();
+
+        Self {
+            size: world.size,
+            cells,
+            ufo: ufo.map(|ufo| ufo.position),
+        }
+    }
+
+    pub fn get(&self, position: IVec2) -> Option<BoardCell> {
+        if position.x < 0
+            || position.y < 0
+            || position.x >= self.size.0 as i32
+            || position.y >= self.size.1 as i32
+        {
+            return None;
+        }
+
+        return self
+            .cells
+            .get((position.y * self.size.0 as i32 + position.x) as usize)
+            .copied();
+    }
+
+    // Entities don't survive the notation, every Object gets a placeholder
+    pub fn to_world(&self) -> World {
+        World {
+            size: self.size,
+            objects: self
+                .cells
+                .iter()
+                .map(|cell| match cell {
+                    BoardCell::Object { id, .. } => Some((Entity::PLACEHOLDER, *id)),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    // Objects are placed by their anchor cells, the mask is left out if it's the default one
+    pub fn to_level_desc(&self, oas: &ObjectAssetServer) -> LevelDesc {
+        let mut desc = LevelDesc::new();
+
+        for (index, cell) in self.cells.iter().enumerate() {
+            if let BoardCell::Object {
+                id, anchor: true, ..
+            } = cell
+            {
+                let position = UVec2::new(index as u32 % self.size.0, index as u32 / self.size.0);
+
+                desc.objects.push(ObjectDesc {
+                    id: *id,
+                    position,
+                    orientation: self.orientation(*id, position, oas),
+                });
+            }
+        }
+
+        let mask: Vec<usize> = self
+            .cells
+            .iter()
+            .map(|cell| if *cell == BoardCell::Masked { 0 } else { 1 })
+            .collect();
+
+        if mask != Grid::default_mask(self.size) {
+            desc.grid = Some(mask);
+        }
+
+        desc.ufo = self.ufo.map(|ufo| UVec2::new(ufo.x as u32, ufo.y as u32));
+
+        return desc;
+    }
+
+    // First orientation whose footprint matches the lowercase cells around the anchor
+    fn orientation(&self, id: ObjectID, anchor: UVec2, oas: &ObjectAssetServer) -> usize {
+        let conf = match oas.get(id) {
+            Ok(asset) => &asset.conf,
+            Err(_) => return 0,
+        };
+
+        for orientation in 0..conf.orientation_count() {
+            let occupied = object::occupied_cells(anchor, conf.occupy(orientation));
+
+            let matches = occupied.iter().skip(1).all(|cell| {
+                return matches!(
+                    self.get(*cell),
+                    Some(BoardCell::Object { id: other, anchor: false, .. }) if other == id
+                );
+            });
+
+            if matches {
+                return orientation;
+            }
+        }
+
+        return 0;
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in (0..self.size.1 as i32).rev() {
+            // Odd rows sit half a cell to the right
+            let mut line = String::from(if y % 2 == 1 { "  " } else { "" });
+
+            for x in 0..self.size.0 as i32 {
+                let position = IVec2::new(x, y);
+                let cell = self.get(position).unwrap_or(BoardCell::Masked);
+
+                let (symbol, carried) = match cell {
+                    BoardCell::Masked => (NOTATION_MASKED, false),
+                    BoardCell::Empty => (NOTATION_EMPTY, false),
+                    BoardCell::Object {
+                        id,
+                        anchor,
+                        carried,
+                    } => {
+                        let symbol = symbol(id).unwrap_or('?');
+                        (
+                            if anchor {
+                                symbol
+                            } else {
+                                symbol.to_ascii_lowercase()
+                            },
+                            carried,
+                        )
+                    }
+                };
+
+                let (open, close) = match (self.ufo == Some(position), carried) {
+                    (true, true) => ('{', '}'),
+                    (true, false) => ('[', ']'),
+                    (false, true) => ('(', ')'),
+                    (false, false) => (' ', ' '),
+                };
+
+                if x > 0 {
+                    line.push(' ');
+                }
+                line.push(open);
+                line.push(symbol);
+                line.push(close);
+            }
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl FromStr for Board {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> GameResult<Self> {
+        let mut rows = vec![];
+
+        for (number, line) in s.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with(NOTATION_COMMENT) {
+                continue;
+            }
+
+            let mut row = vec![];
+            for token in trimmed.split_whitespace() {
+                row.push(parse_token(token).ok_or(GameError::InvalidNotation {
+                    line: number + 1,
+                    reason: format!("`{}` isn't a cell", token),
+                })?);
+            }
+
+            rows.push((number + 1, row));
+        }
+
+        let width = rows.first().map_or(0, |(_, row)| row.len());
+        if width == 0 {
+            return Err(GameError::InvalidNotation {
+                line: 1,
+                reason: "the board is empty".to_string(),
+            });
+        }
+
+        // Rows are written top to bottom, so the last one is y = 0
+        let height = rows.len();
+        let mut cells = vec![BoardCell::Masked; width * height];
+        let mut ufo = None;
+
+        for (i, (number, row)) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GameError::InvalidNotation {
+                    line: number,
+                    reason: format!("expected {} cells, found {}", width, row.len()),
+                });
+            }
+
+            let y = height - 1 - i;
+            for (x, (cell, hovered)) in row.into_iter().enumerate() {
+                if hovered {
+                    if ufo.is_some() {
+                        return Err(GameError::InvalidNotation {
+                            line: number,
+                            reason: "the UFO is on more than one cell".to_string(),
+                        });
+                    }
+                    ufo = Some(IVec2::new(x as i32, y as i32));
+                }
+
+                cells[y * width + x] = cell;
+            }
+        }
+
+        Ok(Self {
+            size: (width as u32, height as u32),
+            cells,
+            ufo,
+        })
+    }
+}
+
+/************************************************************
+ * - System Functions
+ */
+
+fn log_board(
+    objects: Query<(Entity, &Object)>,
+    ufo_query: Query<&UFO>,
+    world: Res<World>,
+    grid: Res<Grid>,
+    keys: Res<Input<KeyCode>>,
+) {
+    if !keys.just_pressed(NOTATION_LOG_KEY) {
+        return;
+    }
+
+    let board = Board::capture(&world, &grid, &objects, ufo_query.get_single().ok());
+
+    info!("Board:\n{}", board);
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+// Uppercase notation letter of an Object
+pub fn symbol(id: ObjectID) -> Option<char> {
+    return NOTATION_SYMBOLS
+        .iter()
+        .find(|(symbol_id, _)| *symbol_id == id)
+        .map(|(_, symbol)| *symbol);
+}
+
+// Cell and whether the UFO hovers it
+fn parse_token(token: &str) -> Option<(BoardCell, bool)> {
+    let chars: Vec<char> = token.chars().collect();
+
+    let (symbol, hovered, carried) = match chars.as_slice() {
+        [symbol] => (*symbol, false, false),
+        ['[', symbol, ']'] => (*symbol, true, false),
+        ['(', symbol, ')'] => (*symbol, false, true),
+        ['{', symbol, '}'] => (*symbol, true, true),
+        _ => return None,
+    };
+
+    let cell = match symbol {
+        NOTATION_EMPTY => BoardCell::Empty,
+        NOTATION_MASKED => BoardCell::Masked,
+        _ => {
+            let (id, _) = NOTATION_SYMBOLS
+                .iter()
+                .find(|(_, s)| *s == symbol.to_ascii_uppercase())?;
+
+            BoardCell::Object {
+                id: *id,
+                anchor: symbol.is_ascii_uppercase(),
+                carried,
+            }
+        }
+    };
+
+    // Only Objects can be carried
+    if carried && !matches!(cell, BoardCell::Object { .. }) {
+        return None;
+    }
+
+    return Some((cell, hovered));
+}
@@ -0,0 +1,321 @@
+use super::{grid::Grid, notation, World};
+use crate::{
+    game::ufo::UFO,
+    object::{
+        asset::ObjectAssetServer,
+        find_valid_cells,
+        turn::{find_path, kill_zone},
+        Object, ObjectID, PlacementRule,
+    },
+    render::{RenderLayer, RENDER_LAYER},
+    state::AppState,
+    ui::bitmap_text::{BitmapText, BitmapTextSystem, TextAlign},
+};
+use bevy::prelude::*;
+
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugOverlay>()
+            .add_systems(
+                PostUpdate,
+                (toggle_debug_overlay, update_debug_overlay)
+                    .chain()
+                    .before(BitmapTextSystem)
+                    .run_if(in_state(AppState::Game)),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_debug_overlay);
+    }
+}
+
+/************************************************************
+ * - Constants
+ */
+
+const OVERLAY_KEY: KeyCode = KeyCode::F3;
+
+const OVERLAY_MARKER_SIZE: f32 = 3.0;
+
+// Top of the two line cell label, relative to the cell center
+const OVERLAY_LABEL_OFFSET: f32 = 8.0;
+
+// Markers sit to the sides of the cell label, relative to the cell center
+const OVERLAY_VALID_OFFSET: (f32, f32) = (-11.0, 0.0);
+const OVERLAY_PATH_OFFSET: (f32, f32) = (11.0, 2.0);
+const OVERLAY_KILL_OFFSET: (f32, f32) = (11.0, -2.0);
+
+const OVERLAY_VALID_COLOR: Color = Color::rgb(0.3, 1.0, 0.3);
+const OVERLAY_PATH_COLOR: Color = Color::rgb(1.0, 0.9, 0.2);
+const OVERLAY_KILL_COLOR: Color = Color::rgb(1.0, 0.25, 0.25);
+const OVERLAY_LABEL_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.8);
+
+// Top left of the info lines, with room for the rule line to wrap once
+const OVERLAY_PANEL_POSITION: (f32, f32) = (2.0, 34.0);
+
+const OVERLAY_PANEL_WIDTH: u32 = 156;
+
+/************************************************************
+ * - Types
+ */
+
+#[derive(Debug, Default, Resource)]
+struct DebugOverlay {
+    active: bool,
+    // UFO position, carried Object with its orientation and occupants the overlay was last built for
+    shown: Option<(
+        IVec2,
+        Option<(Entity, usize)>,
+        Vec<Option<(Entity, ObjectID)>>,
+    )>,
+}
+
+#[derive(Debug, Component)]
+struct DebugOverlayRoot;
+
+/************************************************************
+ * - System Functions
+ */
+
+fn toggle_debug_overlay(
+    mut commands: Commands,
+    mut overlay: ResMut<DebugOverlay>,
+    query: Query<Entity, With<DebugOverlayRoot>>,
+    keys: Res<Input<KeyCode>>,
+) {
+    if !keys.just_pressed(OVERLAY_KEY) {
+        return;
+    }
+
+    overlay.active = !overlay.active;
+    overlay.shown = None;
+
+    if !overlay.active {
+        for entity in &query {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    commands.spawn((
+        SpatialBundle::from_transform(Transform::from_xyz(
+            0.0,
+            0.0,
+            RENDER_LAYER[RenderLayer::Overlay as usize] as f32,
+        )),
+        DebugOverlayRoot,
+        Name::new("Debug Overlay"),
+    ));
+}
+
+fn update_debug_overlay(
+    mut commands: Commands,
+    mut overlay: ResMut<DebugOverlay>,
+    root_query: Query<Entity, With<DebugOverlayRoot>>,
+    objects: Query<(Entity, &Object)>,
+    ufo_query: Query<&UFO>,
+    world: Res<World>,
+    grid: Res<Grid>,
+    oas: Res<ObjectAssetServer>,
+) {
+    let root = match root_query.get_single() {
+        Ok(root) => root,
+        Err(_) => return,
+    };
+    let ufo = match ufo_query.get_single() {
+        Ok(ufo) => ufo,
+        Err(_) => return,
+    };
+
+    // Rebuilding spawns every glyph again, only do it when the board changed
+    let shown = (ufo.position, ufo.carried(), world.objects.clone());
+    if overlay.shown.as_ref() == Some(&shown) {
+        return;
+    }
+    overlay.shown = Some(shown);
+
+    commands.entity(root).despawn_descendants();
+
+    let mut children = vec![];
+
+    // Index and occupant of every cell, with its coordinates below
+    for index in 0..(grid.size.0 * grid.size.1) as usize {
+        if grid.grid[index] == 0 {
+            continue;
+        }
+
+        let symbol = match world.objects[index] {
+            Some((_, id)) => notation::symbol(id).unwrap_or('?'),
+            None => ' ',
+        };
+
+        let center = cell_center(index, &grid);
+        let (x, y) = (index as u32 % grid.size.0, index as u32 / grid.size.0);
+
+        children.push(
+            commands
+                .spawn((
+                    SpatialBundle::from_transform(Transform::from_xyz(
+                        center.x,
+                        center.y + OVERLAY_LABEL_OFFSET,
+                        1.0,
+                    )),
+                    BitmapText::new(&format!("{}{}\n{},{}", index, symbol, x, y))
+                        .with_align(TextAlign::Center)
+                        .with_color(OVERLAY_LABEL_COLOR),
+                    Name::new(format!("Overlay Cell #{}", index)),
+                ))
+                .id(),
+        );
+    }
+
+    // Object the UFO carries or hovers, and the cells it could be dropped on
+    let ufo_index = (ufo.position.y * grid.size.0 as i32 + ufo.position.x) as usize;
+    let target = match ufo.carried() {
+        Some(carried) => Some(carried),
+        None => world.objects[ufo_index].and_then(|(entity, _)| {
+            return Some((entity, objects.get(entity).ok()?.1.orientation));
+        }),
+    };
+
+    let mut rule_line = "RULE -".to_string();
+
+    if let Some((entity, object, orientation)) = target.and_then(|(entity, orientation)| {
+        let (entity, object) = objects.get(entity).ok()?;
+        return Some((entity, object, orientation));
+    }) {
+        if let Some(rule) = PlacementRule::of(object.id) {
+            let valid = find_valid_cells(
+                entity,
+                object.id,
+                object.occupied[0],
+                orientation,
+                &oas,
+                &world,
+                &grid,
+            );
+
+            rule_line = format!("RULE {} ({})", rule, valid.len());
+
+            for cell in valid {
+                children.push(spawn_marker(
+                    cell,
+                    OVERLAY_VALID_OFFSET,
+                    OVERLAY_VALID_COLOR,
+                    &grid,
+                    &mut commands,
+                ));
+            }
+        }
+    }
+
+    // King's path and the Assassins' reach
+    let mut path_line = "PATH NO KING".to_string();
+
+    for (_, object) in &objects {
+        match object.id {
+            ObjectID::King => match find_path(object.occupied[0], &world, &grid) {
+                Some((path, cost)) => {
+                    path_line = format!("PATH {}", cost);
+
+                    for cell in path {
+                        children.push(spawn_marker(
+                            cell,
+                            OVERLAY_PATH_OFFSET,
+                            OVERLAY_PATH_COLOR,
+                            &grid,
+                            &mut commands,
+                        ));
+                    }
+                }
+                None => path_line = "PATH NONE".to_string(),
+            },
+            ObjectID::Assassin => {
+                for cell in kill_zone(object.occupied[0], &grid) {
+                    children.push(spawn_marker(
+                        cell,
+                        OVERLAY_KILL_OFFSET,
+                        OVERLAY_KILL_COLOR,
+                        &grid,
+                        &mut commands,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let occupant = match world.objects[ufo_index] {
+        Some((_, id)) => id.to_string(),
+        None => "EMPTY".to_string(),
+    };
+
+    children.push(
+        commands
+            .spawn((
+                SpatialBundle::from_transform(Transform::from_xyz(
+                    OVERLAY_PANEL_POSITION.0,
+                    OVERLAY_PANEL_POSITION.1,
+                    1.0,
+                )),
+                BitmapText::new(&format!(
+                    "#{} ({},{}) {}\n{}\n{}",
+                    ufo_index, ufo.position.x, ufo.position.y, occupant, path_line, rule_line
+                ))
+                .with_width(OVERLAY_PANEL_WIDTH),
+                Name::new("Overlay Panel"),
+            ))
+            .id(),
+    );
+
+    commands.entity(root).push_children(&children);
+}
+
+fn despawn_debug_overlay(
+    mut commands: Commands,
+    mut overlay: ResMut<DebugOverlay>,
+    query: Query<Entity, With<DebugOverlayRoot>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    *overlay = DebugOverlay::default();
+}
+
+/************************************************************
+ * - Helper Functions
+ */
+
+fn cell_center(index: usize, grid: &Grid) -> Vec2 {
+    return grid.cell_center(UVec2::new(
+        index as u32 % grid.size.0,
+        index as u32 / grid.size.0,
+    ));
+}
+
+fn spawn_marker(
+    cell: IVec2,
+    offset: (f32, f32),
+    color: Color,
+    grid: &Grid,
+    commands: &mut Commands,
+) -> Entity {
+    let index = (cell.y * grid.size.0 as i32 + cell.x) as usize;
+    let center = cell_center(index, grid);
+
+    return commands
+        .spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(OVERLAY_MARKER_SIZE)),
+                    ..Default::default()
+                },
+                transform: Transform::from_xyz(center.x + offset.0, center.y + offset.1, 2.0),
+                ..Default::default()
+            },
+            Name::new("Overlay Marker"),
+        ))
+        .id();
+}
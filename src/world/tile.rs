@@ -1,51 +1,41 @@
-use crate::render::{RenderLayer, RENDER_LAYER};
+use crate::asset::GameAssetServer;
+use crate::render::{RenderLayer, RenderOrder};
+use crate::scene::error::GameErrorEvent;
 use crate::world::grid::Grid;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
-use std::collections::HashMap;
 
 pub struct TilePlugin;
 
 impl Plugin for TilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<TileStateChangeEvent>()
-            .add_systems(PreStartup, setup_tile_asset_server)
+        app.register_type::<Tile>()
+            .add_event::<TileStateChangeEvent>()
             .add_systems(
                 PostUpdate,
-                (update_tile_image, handle_tile_state_change_event),
+                (
+                    update_tile_mask,
+                    update_tile_image,
+                    handle_tile_state_change_event,
+                ),
             );
     }
 }
 
-/************************************************************
- * - Constants
- */
-
-const TILE_ASSET_PATH_EVEN: [&str; 3] = [
-    "tiles/tile_0.png",
-    "tiles/selected_tile_0.png",
-    "tiles/pathtile.png",
-];
-
-const TILE_ASSET_PATH_ODD: [&str; 3] = [
-    "tiles/tile_1.png",
-    "tiles/selected_tile_1.png",
-    "tiles/pathtile.png",
-];
-
 /************************************************************
  * - Types
  */
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Reflect)]
 enum TileType {
+    #[default]
     Even = 0,
     Odd = 1,
 }
 
 impl From<u32> for TileType {
     fn from(value: u32) -> Self {
-        return if value % 2 == 0 {
+        return if value.is_multiple_of(2) {
             Self::Even
         } else {
             Self::Odd
@@ -53,19 +43,6 @@ impl From<u32> for TileType {
     }
 }
 
-#[derive(Debug, Resource)]
-struct TileAssetServer {
-    assets: HashMap<TileType, [Handle<Image>; 3]>,
-}
-
-impl TileAssetServer {
-    fn new() -> Self {
-        Self {
-            assets: HashMap::new(),
-        }
-    }
-}
-
 #[derive(Debug, Event)]
 pub struct TileStateChangeEvent {
     position: IVec2,
@@ -79,18 +56,31 @@ impl TileStateChangeEvent {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum TileState {
+    #[default]
     Default,
     Selected,
     Path,
 }
 
+impl TileState {
+    fn asset_id(&self, r#type: TileType) -> &'static str {
+        return match (self, r#type) {
+            (Self::Default, TileType::Even) => "tile_even",
+            (Self::Default, TileType::Odd) => "tile_odd",
+            (Self::Selected, TileType::Even) => "tile_even_selected",
+            (Self::Selected, TileType::Odd) => "tile_odd_selected",
+            (Self::Path, _) => "tile_path",
+        };
+    }
+}
+
 #[derive(Debug, Component)]
 pub struct TileMap;
 
 impl TileMap {
-    pub fn new(tiles: &Vec<Entity>, commands: &mut Commands) -> Entity {
+    pub fn new(tiles: &[Entity], commands: &mut Commands) -> Entity {
         let tilemap = commands
             .spawn((SpatialBundle::default(), TileMap, Name::new("Tile Map")))
             .id();
@@ -101,7 +91,8 @@ impl TileMap {
     }
 }
 
-#[derive(Debug, Component)]
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
 pub struct Tile {
     pub position: IVec2,
     pub active: bool,
@@ -121,14 +112,13 @@ impl Tile {
                         anchor: Anchor::BottomLeft,
                         ..Default::default()
                     },
-                    transform: Transform::from_xyz(
-                        world_position.x,
-                        world_position.y,
-                        (RENDER_LAYER[RenderLayer::Tile as usize] + grid.cell_order(position))
-                            as f32,
-                    ),
+                    transform: Transform::from_xyz(world_position.x, world_position.y, 0.0),
                     ..Default::default()
                 },
+                RenderOrder::new(
+                    RenderLayer::Tile,
+                    IVec2::new(position.x as i32, position.y as i32),
+                ),
                 Tile {
                     position: IVec2::new(position.x as i32, position.y as i32),
                     active: grid.grid[index] != 0,
@@ -146,33 +136,26 @@ impl Tile {
  * - System Functions
  */
 
-fn setup_tile_asset_server(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let mut tas = TileAssetServer::new();
-
-    tas.assets.insert(
-        TileType::Even,
-        [
-            asset_server.load(TILE_ASSET_PATH_EVEN[0]),
-            asset_server.load(TILE_ASSET_PATH_EVEN[1]),
-            asset_server.load(TILE_ASSET_PATH_EVEN[2]),
-        ],
-    );
-
-    tas.assets.insert(
-        TileType::Odd,
-        [
-            asset_server.load(TILE_ASSET_PATH_ODD[0]),
-            asset_server.load(TILE_ASSET_PATH_ODD[1]),
-            asset_server.load(TILE_ASSET_PATH_ODD[2]),
-        ],
-    );
-
-    commands.insert_resource(tas);
+// Keeps tiles in line with the board mask when it's edited after the tiles were spawned
+fn update_tile_mask(mut query: Query<&mut Tile>, grid: Res<Grid>) {
+    if !grid.is_changed() {
+        return;
+    }
+
+    for mut tile in &mut query {
+        let index = ((tile.position.y * grid.size.0 as i32) + tile.position.x) as usize;
+        let active = grid.grid.get(index).copied().unwrap_or(0) != 0;
+
+        if tile.active != active {
+            tile.active = active;
+        }
+    }
 }
 
 fn update_tile_image(
     mut query: Query<(&Tile, &mut Visibility, &mut Handle<Image>), Changed<Tile>>,
-    tas: Res<TileAssetServer>,
+    mut error_writer: EventWriter<GameErrorEvent>,
+    gas: Res<GameAssetServer>,
 ) {
     for (tile, mut visibility, mut handle) in &mut query {
         if tile.active {
@@ -181,10 +164,9 @@ fn update_tile_image(
             *visibility = Visibility::Hidden;
         }
 
-        match tile.state {
-            TileState::Default => *handle = tas.assets.get(&tile.r#type).unwrap()[0].clone(),
-            TileState::Selected => *handle = tas.assets.get(&tile.r#type).unwrap()[1].clone(),
-            TileState::Path => *handle = tas.assets.get(&tile.r#type).unwrap()[2].clone(),
+        match gas.texture(tile.state.asset_id(tile.r#type)) {
+            Ok(asset) => *handle = asset.handle.clone(),
+            Err(e) => error_writer.send(GameErrorEvent::new(e)),
         }
     }
 }
@@ -0,0 +1,102 @@
+use bevy::{ecs::event::ManualEventReader, prelude::*};
+use gbjam::{
+    animation::{
+        Animate, AnimationClip, AnimationClipDesc, AnimationFrameEvent, AnimationMode,
+        AnimationPlugin, FrameEvent,
+    },
+    settings::Settings,
+};
+use std::time::{Duration, Instant};
+
+const FRAME_INTERVAL: f32 = 0.1;
+
+// Steps are shorter than a frame, so no frame is skipped
+const STEP: Duration = Duration::from_millis(40);
+
+// App playing a two frame `Default` clip on `sprite`
+fn app(sprite: TextureAtlasSprite) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(AnimationPlugin)
+        .init_resource::<Time>()
+        .insert_resource(Settings::default());
+
+    let clip = AnimationClip::from_desc(
+        &AnimationClipDesc {
+            frames: vec![0, 1],
+            durations: vec![],
+            mode: AnimationMode::Default,
+            events: vec![
+                (0, FrameEvent::Signal("first".to_string())),
+                (1, FrameEvent::Signal("last".to_string())),
+            ],
+            next: None,
+        },
+        FRAME_INTERVAL,
+    );
+
+    app.world.spawn((Animate::from_clip(clip), sprite));
+
+    return app;
+}
+
+fn tick(app: &mut App, start: Instant, step: usize) {
+    app.world
+        .resource_mut::<Time>()
+        .update_with_instant(start + STEP * step as u32);
+    app.update();
+}
+
+// Plays the clip for a while and returns the signals it sent
+fn play_signals(steps: usize) -> Vec<String> {
+    let mut app = app(TextureAtlasSprite::default());
+
+    let mut reader = ManualEventReader::<AnimationFrameEvent>::default();
+    let mut signals = vec![];
+    let start = Instant::now();
+
+    for step in 1..=steps {
+        tick(&mut app, start, step);
+
+        let events = app.world.resource::<Events<AnimationFrameEvent>>();
+        for event in reader.iter(events) {
+            if let FrameEvent::Signal(signal) = &event.event {
+                signals.push(signal.clone());
+            }
+        }
+    }
+
+    return signals;
+}
+
+#[test]
+fn first_frame_events_are_sent() {
+    assert_eq!(play_signals(1), vec!["first".to_string()]);
+}
+
+#[test]
+fn finished_default_clip_stays_quiet() {
+    assert_eq!(
+        play_signals(25),
+        vec!["first".to_string(), "last".to_string()]
+    );
+}
+
+#[test]
+fn frames_keep_the_flip() {
+    let mut app = app(TextureAtlasSprite {
+        flip_x: true,
+        ..Default::default()
+    });
+
+    let start = Instant::now();
+    for step in 1..=5 {
+        tick(&mut app, start, step);
+    }
+
+    let mut query = app.world.query::<&TextureAtlasSprite>();
+    let sprite = query.single(&app.world);
+
+    assert_eq!(sprite.index, 1);
+    assert!(sprite.flip_x);
+}
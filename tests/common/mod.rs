@@ -0,0 +1,227 @@
+// Headless harness running the game plugins without a window or renderer
+use bevy::{asset::AssetPlugin as BevyAssetPlugin, ecs::event::ManualEventReader, prelude::*};
+use gbjam::{
+    game::{rng::GameRng, ufo::UFO, win::PlayerWinEvent, GameState},
+    object::{
+        asset::{load_object_confs, ObjectAssetServer},
+        Object, ObjectID,
+    },
+    scene::{
+        editor::EditorLevel,
+        level::{LevelDesc, Score, TurnCounter},
+    },
+    settings::Settings,
+    state::AppState,
+    ui::bitmap_text::BitmapText,
+    world::{
+        grid::Grid,
+        notation::Board,
+        tile::{Tile, TileState},
+        World,
+    },
+    GamePlugins,
+};
+
+/************************************************************
+ * - Constants
+ */
+
+// Frames for an action to work through the events, state changes and score it causes
+const HARNESS_SETTLE_FRAMES: usize = 5;
+
+// Test-play never saves the level, the path only has to exist for the editor
+const HARNESS_LEVEL_PATH: &str = "assets/scn/level_0.ron";
+
+const HARNESS_SEED: u64 = 0;
+
+/************************************************************
+ * - Types
+ */
+
+pub struct GameHarness {
+    pub app: App,
+    win_reader: ManualEventReader<PlayerWinEvent>,
+    wins: usize,
+}
+
+#[allow(dead_code)]
+impl GameHarness {
+    // Plays the level like test-play from the editor, so no records are written
+    pub fn new(desc: LevelDesc) -> Self {
+        let mut app = App::new();
+
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(BevyAssetPlugin::default())
+            .add_plugins(TransformPlugin)
+            .add_plugins(HierarchyPlugin)
+            .add_asset::<Image>()
+            .add_asset::<TextureAtlas>()
+            .add_asset::<AudioSource>()
+            .init_resource::<Input<KeyCode>>()
+            .insert_resource(ClearColor(Color::BLACK))
+            .insert_resource(Settings::default())
+            .insert_resource(EditorLevel::test_play(desc, HARNESS_LEVEL_PATH))
+            .add_plugins(GamePlugins);
+
+        // Skip the preload, splash and title scenes
+        app.world
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Game);
+
+        let mut harness = Self {
+            app,
+            win_reader: ManualEventReader::default(),
+            wins: 0,
+        };
+
+        harness.advance(HARNESS_SETTLE_FRAMES);
+        harness
+            .app
+            .world
+            .resource_mut::<GameRng>()
+            .reseed(HARNESS_SEED);
+
+        return harness;
+    }
+
+    pub fn from_ron(contents: &str) -> Self {
+        let desc = match ron::from_str::<LevelDesc>(contents) {
+            Ok(desc) => desc,
+            Err(e) => panic!("Failed to parse test level, {}.", e),
+        };
+
+        return Self::new(desc);
+    }
+
+    pub fn from_notation(contents: &str) -> Self {
+        let board = match contents.parse::<Board>() {
+            Ok(board) => board,
+            Err(e) => panic!("{}", e),
+        };
+
+        let oas = match load_object_confs() {
+            Ok(confs) => ObjectAssetServer::from_confs(confs),
+            Err(e) => panic!("{}", e),
+        };
+
+        return Self::new(board.to_level_desc(&oas));
+    }
+
+    pub fn update(&mut self) {
+        self.app.update();
+
+        let events = self.app.world.resource::<Events<PlayerWinEvent>>();
+        self.wins += self.win_reader.iter(events).count();
+    }
+
+    pub fn advance(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
+    // Holds the key for a single frame and lets the game react to it
+    pub fn press(&mut self, key: KeyCode) {
+        self.app.world.resource_mut::<Input<KeyCode>>().press(key);
+        self.update();
+
+        let mut input = self.app.world.resource_mut::<Input<KeyCode>>();
+        input.release(key);
+        input.clear();
+
+        self.advance(HARNESS_SETTLE_FRAMES);
+    }
+
+    // Places the UFO directly instead of stepping it across the staggered grid
+    pub fn move_ufo(&mut self, x: i32, y: i32) {
+        let mut query = self.app.world.query::<&mut UFO>();
+        for mut ufo in query.iter_mut(&mut self.app.world) {
+            ufo.position = IVec2::new(x, y);
+        }
+
+        self.advance(HARNESS_SETTLE_FRAMES);
+    }
+
+    // Lifts when the UFO is empty, drops otherwise
+    pub fn lift(&mut self) {
+        let key = self.app.world.resource::<Settings>().input.lift;
+        self.press(key);
+    }
+
+    pub fn cancel(&mut self) {
+        let key = self.app.world.resource::<Settings>().input.cancel;
+        self.press(key);
+    }
+
+    pub fn rotate(&mut self) {
+        let key = self.app.world.resource::<Settings>().input.rotate;
+        self.press(key);
+    }
+
+    pub fn carry(&mut self, from: (i32, i32), to: (i32, i32)) {
+        self.move_ufo(from.0, from.1);
+        self.lift();
+        self.move_ufo(to.0, to.1);
+        self.lift();
+    }
+
+    pub fn object_at(&self, x: i32, y: i32) -> Option<ObjectID> {
+        let world = self.app.world.resource::<World>();
+        let index = (y * world.size.0 as i32 + x) as usize;
+
+        return world.objects[index].map(|(_, id)| id);
+    }
+
+    pub fn tile_state(&mut self, x: i32, y: i32) -> Option<TileState> {
+        let mut query = self.app.world.query::<&Tile>();
+
+        return query
+            .iter(&self.app.world)
+            .find(|tile| tile.position == IVec2::new(x, y))
+            .map(|tile| tile.state);
+    }
+
+    // Every piece of bitmap text on screen
+    pub fn texts(&mut self) -> Vec<String> {
+        let mut query = self.app.world.query::<&BitmapText>();
+
+        return query
+            .iter(&self.app.world)
+            .map(|text| text.text.clone())
+            .collect();
+    }
+
+    pub fn board(&mut self) -> Board {
+        let mut objects = self.app.world.query::<(Entity, &Object)>();
+        let mut ufos = self.app.world.query::<&UFO>();
+
+        let world = &self.app.world;
+
+        return Board::capture(
+            world.resource::<World>(),
+            world.resource::<Grid>(),
+            objects.iter(world),
+            ufos.iter(world).next(),
+        );
+    }
+
+    pub fn score(&self) -> usize {
+        return self.app.world.resource::<Score>().current;
+    }
+
+    pub fn turn(&self) -> usize {
+        return self.app.world.resource::<TurnCounter>().turn;
+    }
+
+    pub fn won(&self) -> bool {
+        return self.wins > 0;
+    }
+
+    pub fn state(&self) -> AppState {
+        return *self.app.world.resource::<State<AppState>>().get();
+    }
+
+    pub fn game_state(&self) -> GameState {
+        return *self.app.world.resource::<State<GameState>>().get();
+    }
+}
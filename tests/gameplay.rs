@@ -0,0 +1,140 @@
+mod common;
+
+use common::GameHarness;
+use gbjam::{game::GameState, object::ObjectID, world::tile::TileState};
+
+// King at (2, 4) is walled in by Mountains and a House at (2, 5),
+// moving the House away opens the way to the Castle.
+const ENCLOSED_KING_LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 4)),
+        (id: Castle, position: (4, 8)),
+        (id: Mountain, position: (2, 3)),
+        (id: Mountain, position: (1, 3)),
+        (id: Mountain, position: (1, 5)),
+        (id: House, position: (2, 5)),
+        (id: Cow, position: (3, 1)),
+        (id: Farm, position: (4, 1)),
+    ],
+)
+"#;
+
+// Church standing upright at (2, 1), covering (2, 1) and (3, 2)
+const CHURCH_LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 6)),
+        (id: Castle, position: (4, 8)),
+        (id: Church, position: (2, 1)),
+    ],
+)
+"#;
+
+#[test]
+fn level_loads_into_world() {
+    let harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    assert_eq!(harness.object_at(2, 4), Some(ObjectID::King));
+    assert_eq!(harness.object_at(4, 8), Some(ObjectID::Castle));
+    assert_eq!(harness.object_at(3, 1), Some(ObjectID::Cow));
+    assert_eq!(harness.turn(), 0);
+    assert_eq!(harness.score(), 0);
+}
+
+#[test]
+fn dropping_cow_on_farm_scores_one() {
+    let mut harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    harness.carry((3, 1), (4, 1));
+
+    assert_eq!(harness.turn(), 1);
+    assert_eq!(harness.score(), 1);
+    assert_eq!(harness.object_at(3, 1), None);
+    assert_eq!(harness.object_at(4, 1), Some(ObjectID::Farm));
+    assert!(!harness.won());
+}
+
+#[test]
+fn invalid_drop_keeps_the_turn() {
+    let mut harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    // Mountains can't be built over
+    harness.carry((3, 1), (2, 3));
+
+    assert_eq!(harness.turn(), 0);
+    assert_eq!(harness.object_at(2, 3), Some(ObjectID::Mountain));
+
+    harness.cancel();
+
+    assert_eq!(harness.object_at(3, 1), Some(ObjectID::Cow));
+}
+
+#[test]
+fn opening_the_path_wins() {
+    let mut harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    harness.carry((2, 5), (3, 5));
+
+    assert_eq!(harness.turn(), 1);
+    assert_eq!(harness.object_at(3, 5), Some(ObjectID::House));
+    assert!(harness.won());
+}
+
+// Objects only act while `GameState::ObjectControlled`, the King finding the Castle is part of their turn
+#[test]
+fn drop_hands_the_turn_to_the_objects_and_back() {
+    let mut harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    harness.carry((3, 1), (4, 1));
+
+    assert_eq!(harness.turn(), 1);
+    assert_eq!(harness.game_state(), GameState::PlayerControlled);
+    assert!(!harness.won());
+
+    harness.carry((2, 5), (3, 5));
+
+    assert_eq!(harness.turn(), 2);
+    assert!(harness.won());
+}
+
+#[test]
+fn carrying_previews_the_kings_path() {
+    let mut harness = GameHarness::from_ron(ENCLOSED_KING_LEVEL);
+
+    harness.move_ufo(2, 5);
+    harness.lift();
+
+    assert!(harness.texts().contains(&"PATH BLOCKED".to_string()));
+    assert_eq!(harness.tile_state(2, 4), Some(TileState::Default));
+
+    harness.move_ufo(3, 5);
+
+    assert!(harness.texts().contains(&"PATH OPEN".to_string()));
+    assert_eq!(harness.tile_state(2, 4), Some(TileState::Path));
+    assert_eq!(harness.turn(), 0);
+
+    harness.cancel();
+
+    assert!(!harness.texts().iter().any(|text| text.starts_with("PATH")));
+    assert_ne!(harness.tile_state(2, 4), Some(TileState::Path));
+}
+
+#[test]
+fn rotated_church_keeps_its_orientation() {
+    let mut harness = GameHarness::from_ron(CHURCH_LEVEL);
+
+    assert_eq!(harness.object_at(3, 2), Some(ObjectID::Church));
+
+    harness.move_ufo(2, 1);
+    harness.lift();
+    harness.rotate();
+    harness.move_ufo(1, 1);
+    harness.lift();
+
+    assert_eq!(harness.turn(), 1);
+    assert_eq!(harness.object_at(1, 1), Some(ObjectID::Church));
+    assert_eq!(harness.object_at(1, 2), Some(ObjectID::Church));
+    assert_eq!(harness.object_at(2, 2), None);
+    assert_eq!(harness.object_at(2, 1), None);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use common::GameHarness;
+use gbjam::state::AppState;
+
+const HUD_IMAGE_TYPO_LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 4)),
+        (id: Castle, position: (4, 8)),
+    ],
+    hud: [
+        (kind: Image("ui_bakground"), position: (0, 0)),
+    ],
+)
+"#;
+
+const HUD_FONT_TYPO_LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 4)),
+        (id: Castle, position: (4, 8)),
+    ],
+    hud: [
+        (kind: Text("HELLO"), position: (8, 24), font: "fnt"),
+    ],
+)
+"#;
+
+#[test]
+fn unknown_hud_texture_is_reported() {
+    let harness = GameHarness::from_ron(HUD_IMAGE_TYPO_LEVEL);
+
+    assert_eq!(harness.state(), AppState::Error);
+}
+
+#[test]
+fn unknown_hud_font_is_reported() {
+    let harness = GameHarness::from_ron(HUD_FONT_TYPO_LEVEL);
+
+    assert_eq!(harness.state(), AppState::Error);
+}
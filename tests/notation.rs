@@ -0,0 +1,98 @@
+mod common;
+
+use bevy::prelude::IVec2;
+use common::GameHarness;
+use gbjam::{
+    object::ObjectID,
+    world::notation::{Board, BoardCell},
+};
+
+// Same board as the enclosed King level of the gameplay tests, with the UFO at its default start
+const ENCLOSED_KING_BOARD: &str = "
+    // y = 8 at the top, odd rows are shifted right
+     -   .   .   .   G
+       .   .   .   .   .
+     -  [.]  .   .   .
+       .   M   H   .   .
+     -   .   K   .   .
+       .   M   M   .   .
+     -   .   .   .   .
+       .   .   .   C   F
+     -   .   .   .   .
+";
+
+// Church anchored at (2, 1) and turned, so its second cell is (2, 2) instead of (3, 2)
+const ROTATED_CHURCH_BOARD: &str = "
+     -   .   .   .   G
+       .   .   .   .   .
+     -  [.]  K   .   .
+       .   .   .   .   .
+     -   .   .   .   .
+       .   .   .   .   .
+     -   .   r   .   .
+       .   .   R   .   .
+     -   .   .   .   .
+";
+
+#[test]
+fn notation_round_trips() {
+    let board: Board = ENCLOSED_KING_BOARD.parse().unwrap();
+
+    assert_eq!(board.size, (5, 9));
+    assert_eq!(board.ufo, Some(IVec2::new(1, 6)));
+    assert_eq!(board.get(IVec2::new(0, 8)), Some(BoardCell::Masked));
+    assert_eq!(
+        board.get(IVec2::new(2, 4)),
+        Some(BoardCell::Object {
+            id: ObjectID::King,
+            anchor: true,
+            carried: false,
+        })
+    );
+
+    assert_eq!(board.to_string().parse::<Board>().unwrap(), board);
+}
+
+#[test]
+fn rows_of_different_widths_are_rejected() {
+    assert!(" .  .\n .\n".parse::<Board>().is_err());
+    assert!(" .  ?\n".parse::<Board>().is_err());
+}
+
+#[test]
+fn game_matches_the_notation_it_was_loaded_from() {
+    let mut harness = GameHarness::from_notation(ENCLOSED_KING_BOARD);
+
+    let board: Board = ENCLOSED_KING_BOARD.parse().unwrap();
+
+    assert_eq!(harness.board(), board);
+}
+
+#[test]
+fn carried_object_is_marked() {
+    let mut harness = GameHarness::from_notation(ENCLOSED_KING_BOARD);
+
+    harness.move_ufo(3, 1);
+    harness.lift();
+
+    assert_eq!(
+        harness.board().get(IVec2::new(3, 1)),
+        Some(BoardCell::Object {
+            id: ObjectID::Cow,
+            anchor: true,
+            carried: true,
+        })
+    );
+    assert!(harness.board().to_string().contains("{C}"));
+}
+
+#[test]
+fn orientation_is_read_from_the_footprint() {
+    let mut harness = GameHarness::from_notation(ROTATED_CHURCH_BOARD);
+
+    let board: Board = ROTATED_CHURCH_BOARD.parse().unwrap();
+
+    assert_eq!(harness.object_at(2, 2), Some(ObjectID::Church));
+    assert_eq!(harness.object_at(3, 2), None);
+    assert_eq!(harness.board(), board);
+}
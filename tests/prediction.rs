@@ -0,0 +1,84 @@
+use bevy::prelude::IVec2;
+use gbjam::{
+    object::{
+        turn::{predict_turn, TurnPrediction},
+        ObjectID,
+    },
+    world::{
+        grid::Grid,
+        notation::{Board, BoardCell},
+    },
+};
+
+// Cow (3, 1) next to its Farm, Villager (2, 6) in the open and an Assassin (2, 3) beside the King
+const TURN_BOARD: &str = "
+     -   .   .   .   G
+       .   .   .   .   .
+     -   .   V   .   .
+       .   .   .   .   .
+     -   .   .   .   .
+       .   K   A   .   .
+     -   .   .   .   .
+       .   .   .   C   F
+     -   .   .   .   .
+";
+
+fn predict(board: &Board, housed: &[(ObjectID, IVec2)]) -> TurnPrediction {
+    let mut units = housed.to_vec();
+
+    for y in 0..board.size.1 as i32 {
+        for x in 0..board.size.0 as i32 {
+            let position = IVec2::new(x, y);
+
+            if let Some(BoardCell::Object { id, .. }) = board.get(position) {
+                if matches!(id, ObjectID::Villager | ObjectID::Cow | ObjectID::Assassin) {
+                    units.push((id, position));
+                }
+            }
+        }
+    }
+
+    return predict_turn(&units, &board.to_world(), &Grid::default());
+}
+
+#[test]
+fn assassin_kills_the_king_beside_it() {
+    let board: Board = TURN_BOARD.parse().unwrap();
+
+    let prediction = predict(&board, &[]);
+
+    assert_eq!(prediction.killed, vec![IVec2::new(1, 3)]);
+    assert!(prediction.consumed.is_empty());
+}
+
+#[test]
+fn open_villager_wanders_evenly() {
+    let board: Board = TURN_BOARD.parse().unwrap();
+
+    let prediction = predict(&board, &[]);
+
+    // Even row, the diagonal cells of (2, 6) are all free
+    for cell in [(2, 7), (2, 5), (1, 7), (1, 5)] {
+        let (_, chance) = prediction
+            .wander
+            .iter()
+            .find(|(position, _)| *position == IVec2::new(cell.0, cell.1))
+            .unwrap();
+
+        assert!((chance - 0.15).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn cow_on_farm_is_consumed() {
+    let board: Board = TURN_BOARD.parse().unwrap();
+
+    // The Cow was dropped onto the Farm, which keeps the cell in the World
+    let prediction = predict(&board, &[(ObjectID::Cow, IVec2::new(4, 1))]);
+
+    assert!(prediction.consumed.contains(&IVec2::new(4, 1)));
+    assert!(!prediction
+        .wander
+        .iter()
+        .any(|(cell, _)| *cell == IVec2::new(4, 1)));
+}
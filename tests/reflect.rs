@@ -0,0 +1,108 @@
+mod common;
+
+use bevy::{prelude::*, reflect::GetPath};
+use common::GameHarness;
+use gbjam::{
+    animation::Animate,
+    game::ufo::UFO,
+    object::{Object, ObjectID, Selectable},
+    render::RenderOrder,
+    scene::level::{Level, Score, TurnCounter},
+    world::{grid::Grid, tile::Tile, World},
+};
+use std::any::TypeId;
+
+const COW_LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 4)),
+        (id: Castle, position: (4, 8)),
+        (id: Cow, position: (3, 1)),
+    ],
+)
+"#;
+
+#[test]
+fn game_state_is_registered() {
+    let harness = GameHarness::from_ron(COW_LEVEL);
+
+    let registry = harness.app.world.resource::<AppTypeRegistry>().read();
+
+    for type_id in [
+        TypeId::of::<Object>(),
+        TypeId::of::<UFO>(),
+        TypeId::of::<Tile>(),
+        TypeId::of::<Selectable>(),
+        TypeId::of::<Animate>(),
+        TypeId::of::<World>(),
+        TypeId::of::<Grid>(),
+        TypeId::of::<Score>(),
+        TypeId::of::<Level>(),
+        TypeId::of::<TurnCounter>(),
+    ] {
+        assert!(registry.get(type_id).is_some());
+    }
+}
+
+#[test]
+fn editing_occupied_moves_the_object() {
+    let mut harness = GameHarness::from_ron(COW_LEVEL);
+
+    let entity = {
+        let world = harness.app.world.resource::<World>();
+        let index = (world.size.0 + 3) as usize;
+        world.objects[index].unwrap().0
+    };
+
+    // Same access the inspector has
+    let mut object = harness.app.world.get_mut::<Object>(entity).unwrap();
+    *object.path_mut::<IVec2>("occupied[0]").unwrap() = IVec2::new(2, 2);
+
+    harness.advance(1);
+
+    assert_eq!(harness.object_at(3, 1), None);
+    assert_eq!(harness.object_at(2, 2), Some(ObjectID::Cow));
+    assert_eq!(
+        harness.app.world.get::<RenderOrder>(entity).unwrap().cell,
+        IVec2::new(2, 2)
+    );
+}
+
+#[test]
+fn invalid_edit_is_undone() {
+    let mut harness = GameHarness::from_ron(COW_LEVEL);
+
+    let entity = {
+        let world = harness.app.world.resource::<World>();
+        let index = (world.size.0 + 3) as usize;
+        world.objects[index].unwrap().0
+    };
+    let translation = harness
+        .app
+        .world
+        .get::<Transform>(entity)
+        .unwrap()
+        .translation;
+
+    // Onto the King
+    let mut object = harness.app.world.get_mut::<Object>(entity).unwrap();
+    *object.path_mut::<IVec2>("occupied[0]").unwrap() = IVec2::new(2, 4);
+
+    harness.advance(1);
+
+    assert_eq!(harness.object_at(3, 1), Some(ObjectID::Cow));
+    assert_eq!(harness.object_at(2, 4), Some(ObjectID::King));
+    assert_eq!(
+        harness.app.world.get::<Object>(entity).unwrap().occupied,
+        vec![IVec2::new(3, 1)]
+    );
+    assert_eq!(
+        harness
+            .app
+            .world
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation,
+        translation
+    );
+}
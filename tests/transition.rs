@@ -0,0 +1,98 @@
+mod common;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use common::GameHarness;
+use gbjam::state::{
+    transition::{SceneTransitionEvent, TransitionEffect, TransitionMode},
+    AppState,
+};
+use std::time::Duration;
+
+const LEVEL: &str = r#"
+LevelDesc(
+    objects: [
+        (id: King, position: (2, 4)),
+        (id: Castle, position: (4, 8)),
+    ],
+)
+"#;
+
+// Each half of the procedural effects takes 0.6 seconds, a bit over 12 steps
+const STEP: Duration = Duration::from_millis(50);
+
+const TRANSITION_STEPS: usize = 20;
+
+// Inserted by the transition callbacks
+#[derive(Debug, Resource)]
+struct Covered;
+
+fn harness() -> GameHarness {
+    let mut harness = GameHarness::from_ron(LEVEL);
+
+    harness
+        .app
+        .insert_resource(TimeUpdateStrategy::ManualDuration(STEP));
+
+    return harness;
+}
+
+fn send(harness: &mut GameHarness, mode: TransitionMode) {
+    let event = SceneTransitionEvent::new(TransitionEffect::Checkerboard, AppState::Game)
+        .with_mode(mode)
+        .with_callback(|world: &mut World| world.insert_resource(Covered));
+
+    harness
+        .app
+        .world
+        .resource_mut::<Events<SceneTransitionEvent>>()
+        .send(event);
+}
+
+// Transition effects being played, or kept covering the screen
+fn effects(harness: &mut GameHarness) -> usize {
+    let mut query = harness.app.world.query::<&Name>();
+
+    return query
+        .iter(&harness.app.world)
+        .filter(|name| name.as_str() == "Scene Transition Effect")
+        .count();
+}
+
+#[test]
+fn out_transition_runs_its_callback_and_stays_covered() {
+    let mut harness = harness();
+
+    send(&mut harness, TransitionMode::Out);
+    harness.advance(2);
+
+    assert_eq!(harness.state(), AppState::Transition);
+    assert!(!harness.app.world.contains_resource::<Covered>());
+
+    harness.advance(TRANSITION_STEPS);
+
+    assert_eq!(harness.state(), AppState::Game);
+    assert!(harness.app.world.contains_resource::<Covered>());
+    assert_eq!(effects(&mut harness), 1);
+}
+
+#[test]
+fn in_transition_switches_right_away_and_uncovers() {
+    let mut harness = harness();
+
+    send(&mut harness, TransitionMode::Out);
+    harness.advance(TRANSITION_STEPS);
+    harness.app.world.remove_resource::<Covered>();
+
+    send(&mut harness, TransitionMode::In);
+    harness.advance(2);
+
+    // The cover left by the `Out` transition is replaced
+    assert_eq!(harness.state(), AppState::Game);
+    assert!(harness.app.world.contains_resource::<Covered>());
+    assert_eq!(effects(&mut harness), 1);
+
+    harness.advance(TRANSITION_STEPS);
+
+    assert_eq!(harness.state(), AppState::Game);
+    assert_eq!(effects(&mut harness), 0);
+}